@@ -0,0 +1,115 @@
+use std::{collections::BTreeMap, fs};
+
+use anyhow::{anyhow, Context, Error};
+use puzzle::Answer;
+
+/// Default answers file, at the root of the workspace
+pub static ANSWERS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.toml");
+
+/// Known-correct answers, keyed by day and part
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, u8), Answer>,
+}
+
+impl Answers {
+    /// Load an answers file, an empty set of answers if it does not exist
+    pub fn load(path: &str) -> Result<Self, Error> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).with_context(|| format!("Bad answers file '{}'", path)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e).with_context(|| format!("Cannot read answers file '{}'", path)),
+        }
+    }
+
+    fn parse(text: &str) -> Result<Self, Error> {
+        let table: toml::Table = text.parse()?;
+
+        let mut answers = BTreeMap::new();
+        for (day_key, parts) in table {
+            let day = day_key
+                .strip_prefix("day")
+                .and_then(|day| day.parse().ok())
+                .ok_or(anyhow!("Bad day '{}'", day_key))?;
+            let parts = parts
+                .as_table()
+                .ok_or(anyhow!("'{}' should be a table", day_key))?;
+
+            for (part_key, answer) in parts {
+                let part = part_key
+                    .strip_prefix("part")
+                    .and_then(|part| part.parse().ok())
+                    .ok_or(anyhow!("Bad part '{}' in '{}'", part_key, day_key))?;
+                let answer = match answer {
+                    toml::Value::Integer(n) => Answer::Number(*n as i128),
+                    toml::Value::String(s) => Answer::Text(s.clone()),
+                    _ => return Err(anyhow!("Bad answer for {}.{}", day_key, part_key)),
+                };
+                answers.insert((day, part), answer);
+            }
+        }
+
+        Ok(Self { answers })
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), Error> {
+        let mut text = String::from(
+            "# Known-correct answers for the inputs in each day's input.txt, checked by `aoc verify`\n",
+        );
+        let mut current_day = None;
+        for ((day, part), answer) in &self.answers {
+            if current_day != Some(day) {
+                text += &format!("\n[day{}]\n", day);
+                current_day = Some(day);
+            }
+            let answer = match answer {
+                Answer::Number(n) => n.to_string(),
+                Answer::Text(t) => toml::Value::String(t.clone()).to_string(),
+            };
+            text += &format!("part{} = {}\n", part, answer);
+        }
+
+        fs::write(path, text).with_context(|| format!("Cannot write answers file '{}'", path))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        self.answers.get(&(day, part))
+    }
+
+    pub fn insert(&mut self, day: u8, part: u8, answer: Answer) {
+        self.answers.insert((day, part), answer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() -> Result<(), Error> {
+        let mut answers = Answers::default();
+        answers.insert(10, 2, Answer::Number(417));
+        answers.insert(2, 1, Answer::Number(2720));
+        answers.insert(2, 2, Answer::Text("EZ\"PZ".to_string()));
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.toml", std::process::id()));
+        let path = path.to_str().unwrap();
+
+        answers.save(path)?;
+        let loaded = Answers::load(path)?;
+        fs::remove_file(path)?;
+
+        assert_eq!(answers, loaded);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_workspace_answers() -> Result<(), Error> {
+        let answers = Answers::load(ANSWERS_PATH)?;
+
+        assert_eq!(Some(&Answer::Number(56049)), answers.get(1, 1));
+        assert_eq!(None, answers.get(1, 3));
+
+        Ok(())
+    }
+}
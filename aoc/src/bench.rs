@@ -0,0 +1,185 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, Context, Error};
+use puzzle::Solver;
+
+/// Median time of each stage of a solver
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    pub const STAGES: [&'static str; 3] = ["parse", "part1", "part2"];
+
+    fn stages(&self) -> [Duration; 3] {
+        [self.parse, self.part1, self.part2]
+    }
+
+    fn total(&self) -> Duration {
+        self.stages().iter().sum()
+    }
+}
+
+fn median(mut durations: Vec<Duration>) -> Duration {
+    durations.sort();
+    durations[durations.len() / 2]
+}
+
+/// Time parsing and both parts of a solver, `runs` times each
+pub fn run<S: Solver>(input: &str, runs: u32) -> Result<Timings, Error> {
+    let mut parse = Vec::new();
+    let mut part1 = Vec::new();
+    let mut part2 = Vec::new();
+
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        let puzzle = black_box(S::parse(input)?);
+        parse.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(puzzle.part1()?);
+        part1.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(puzzle.part2()?);
+        part2.push(start.elapsed());
+    }
+
+    Ok(Timings {
+        parse: median(parse),
+        part1: median(part1),
+        part2: median(part2),
+    })
+}
+
+/// Timings of all the benchmarked days
+#[derive(Debug, Default)]
+pub struct Report {
+    days: BTreeMap<u8, Timings>,
+}
+
+impl Report {
+    pub fn insert(&mut self, day: u8, timings: Timings) {
+        self.days.insert(day, timings);
+    }
+
+    /// Save the report as a baseline, one `day stage nanoseconds` line per measure
+    pub fn save(&self, path: &str) -> Result<(), Error> {
+        let mut text = String::new();
+        for (day, timings) in &self.days {
+            for (stage, duration) in Timings::STAGES.iter().zip(timings.stages()) {
+                text += &format!("{} {} {}\n", day, stage, duration.as_nanos());
+            }
+        }
+        fs::write(path, text).with_context(|| format!("Cannot write baseline '{}'", path))
+    }
+
+    pub fn load(path: &str) -> Result<Self, Error> {
+        let text =
+            fs::read_to_string(path).with_context(|| format!("Cannot read baseline '{}'", path))?;
+
+        let mut stages: BTreeMap<u8, [Duration; 3]> = BTreeMap::new();
+        for line in text.lines().filter(|line| !line.is_empty()) {
+            let mut fields = line.split(' ');
+            let (Some(day), Some(stage), Some(nanos), None) =
+                (fields.next(), fields.next(), fields.next(), fields.next())
+            else {
+                return Err(anyhow!("Bad baseline line '{}'", line));
+            };
+            let stage = Timings::STAGES
+                .iter()
+                .position(|s| *s == stage)
+                .ok_or(anyhow!("Unknown stage '{}' in baseline", stage))?;
+            let duration = Duration::from_nanos(nanos.parse()?);
+            stages.entry(day.parse()?).or_default()[stage] = duration;
+        }
+
+        let days = stages
+            .into_iter()
+            .map(|(day, [parse, part1, part2])| (day, Timings { parse, part1, part2 }))
+            .collect();
+
+        Ok(Self { days })
+    }
+
+    /// Print the timings as a table, with the change from the baseline if there is one
+    pub fn print(&self, baseline: Option<&Report>) {
+        println!(
+            "{:>4}  {:>22}  {:>22}  {:>22}  {:>22}",
+            "Day", "Parse", "Part 1", "Part 2", "Total"
+        );
+
+        for (day, timings) in &self.days {
+            let previous = baseline.and_then(|b| b.days.get(day));
+
+            let mut columns = timings.stages().to_vec();
+            columns.push(timings.total());
+            let previous_columns = previous.map(|p| {
+                let mut columns = p.stages().to_vec();
+                columns.push(p.total());
+                columns
+            });
+
+            print!("{:>4}", day);
+            for (i, duration) in columns.iter().enumerate() {
+                let change = previous_columns
+                    .as_ref()
+                    .map(|p| format_change(p[i], *duration))
+                    .unwrap_or_default();
+                print!("  {:>12} {:>9}", format!("{:.1?}", duration), change);
+            }
+            println!();
+        }
+    }
+}
+
+fn format_change(before: Duration, after: Duration) -> String {
+    if before.is_zero() {
+        return String::new();
+    }
+    let change = (after.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0;
+    format!("{:+.1}%", change)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_baseline_round_trip() -> Result<(), Error> {
+        let mut report = Report::default();
+        report.insert(
+            3,
+            Timings {
+                parse: Duration::from_micros(12),
+                part1: Duration::from_nanos(3456),
+                part2: Duration::from_millis(7),
+            },
+        );
+        let path = std::env::temp_dir().join(format!("aoc-baseline-{}.txt", std::process::id()));
+        let path = path.to_str().unwrap();
+
+        report.save(path)?;
+        let loaded = Report::load(path)?;
+        fs::remove_file(path)?;
+
+        assert_eq!(report.days, loaded.days);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_change() {
+        assert_eq!("+50.0%", format_change(Duration::from_millis(2), Duration::from_millis(3)));
+        assert_eq!("-25.0%", format_change(Duration::from_millis(4), Duration::from_millis(3)));
+        assert_eq!("", format_change(Duration::ZERO, Duration::from_millis(3)));
+    }
+}
@@ -0,0 +1,95 @@
+use std::ops::RangeInclusive;
+
+use anyhow::{anyhow, Error};
+use puzzle::{Answer, Generator, Solver};
+
+use crate::bench::{self, Timings};
+
+/// Both puzzle parts
+pub const PARTS: RangeInclusive<u8> = 1..=2;
+
+/// Size of the generated inputs when none is given
+pub const DEFAULT_SIZE: usize = 100;
+
+/// A solved day of the calendar
+pub struct Day {
+    pub day: u8,
+    /// Default puzzle input
+    pub input_path: &'static str,
+    /// Parse an input and solve one part of it
+    pub solve: fn(&str, u8) -> Result<Answer, Error>,
+    /// Time each stage of the solver on an input
+    pub bench: fn(&str, u32) -> Result<Timings, Error>,
+    /// Random input of some size, from a seed
+    pub generate: fn(usize, u64) -> String,
+    /// Largest size of the random inputs
+    pub max_size: usize,
+}
+
+macro_rules! day {
+    ($day:literal, $krate:ident :: $solver:ident) => {
+        Day {
+            day: $day,
+            input_path: $krate::INPUT_PATH,
+            solve: $krate::$solver::solve,
+            bench: bench::run::<$krate::$solver>,
+            generate: <$krate::$solver as Generator>::generate,
+            max_size: <$krate::$solver as Generator>::MAX_SIZE,
+        }
+    };
+}
+
+pub static DAYS: [Day; 11] = [
+    day!(1, day1::Document),
+    day!(2, day2::Games),
+    day!(3, day3::Schematic),
+    day!(4, day4::Cards),
+    day!(5, day5::Almanac),
+    day!(6, day6::Sheet),
+    day!(7, day7::CamelCards),
+    day!(8, day8::Map),
+    day!(9, day9::Report),
+    day!(10, day10::Map),
+    day!(11, day11::Image),
+];
+
+/// Find a day by its number
+pub fn get(day: u8) -> Result<&'static Day, Error> {
+    DAYS.iter()
+        .find(|d| d.day == day)
+        .ok_or(anyhow!("Day {} is not solved (yet?)", day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated_inputs_are_solved() -> Result<(), Error> {
+        for day in &DAYS {
+            for seed in 0..10 {
+                let input = (day.generate)(12, seed);
+                for part in PARTS {
+                    (day.solve)(&input, part).map_err(|e| {
+                        anyhow!("Day {} part {}, seed {}: {}\n{}", day.day, part, seed, e, input)
+                    })?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_default_size_inputs_are_solved() -> Result<(), Error> {
+        for day in &DAYS {
+            let input = (day.generate)(DEFAULT_SIZE, 0);
+            for part in PARTS {
+                (day.solve)(&input, part)
+                    .map_err(|e| anyhow!("Day {} part {}: {}", day.day, part, e))?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,218 @@
+use std::time::Instant;
+
+use anyhow::{anyhow, Error};
+use clap::{Parser, Subcommand};
+use output::{Format, Record};
+
+mod answers;
+mod bench;
+mod days;
+mod output;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2023 solutions runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solvers and print the answers
+    Run {
+        /// Day to run, all days if not set
+        #[arg(short, long)]
+        day: Option<u8>,
+        /// Part to run, both parts if not set
+        #[arg(short, long)]
+        part: Option<u8>,
+        /// Puzzle input file, `-` for stdin. Defaults to the day's input.txt, requires --day
+        #[arg(short, long, requires = "day")]
+        input: Option<String>,
+        /// Output format of the answers
+        #[arg(short, long, value_enum, default_value_t)]
+        format: Format,
+    },
+    /// Time parsing and both parts of the solvers
+    Bench {
+        /// Day to benchmark, all days if not set
+        #[arg(short, long)]
+        day: Option<u8>,
+        /// Number of runs of each stage, the median is reported
+        #[arg(short, long, default_value_t = 10)]
+        runs: u32,
+        /// Baseline file to compare the timings with
+        #[arg(short, long)]
+        baseline: Option<String>,
+        /// Save the timings as a baseline file
+        #[arg(short, long)]
+        save: Option<String>,
+    },
+    /// Print a random puzzle input
+    Generate {
+        /// Day of the input
+        #[arg(short, long)]
+        day: u8,
+        /// Size of the input, lines, cards, side of the grid... depending on the day.
+        /// Some days cap it, day 6 has at most 9 races, a warning is printed then
+        #[arg(short, long, default_value_t = days::DEFAULT_SIZE)]
+        size: usize,
+        /// Seed of the random generator, the same seed gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Check the solvers answers on their inputs against the known-correct answers
+    Verify {
+        /// Day to verify, all days if not set
+        #[arg(short, long)]
+        day: Option<u8>,
+        /// Answers file, defaults to answers.toml at the root of the workspace
+        #[arg(short, long)]
+        answers: Option<String>,
+        /// Record the current answers as the correct ones instead of checking them
+        #[arg(short, long)]
+        record: bool,
+    },
+}
+
+fn run(
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<String>,
+    format: Format,
+) -> Result<(), Error> {
+    let days = match day {
+        Some(day) => vec![days::get(day)?],
+        None => days::DAYS.iter().collect(),
+    };
+
+    for day in days {
+        let input = puzzle::read_input(input.as_deref().unwrap_or(day.input_path))?;
+
+        let parts = part.map_or(days::PARTS, |part| part..=part);
+        for part in parts {
+            if !days::PARTS.contains(&part) {
+                return Err(anyhow!("Part {} does not exist", part));
+            }
+
+            let start = Instant::now();
+            let answer = (day.solve)(&input, part)?;
+            let record = Record {
+                day: day.day,
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            };
+            println!("{}", record.format(format));
+        }
+    }
+
+    Ok(())
+}
+
+fn bench(
+    day: Option<u8>,
+    runs: u32,
+    baseline: Option<String>,
+    save: Option<String>,
+) -> Result<(), Error> {
+    let days = match day {
+        Some(day) => vec![days::get(day)?],
+        None => days::DAYS.iter().collect(),
+    };
+
+    let baseline = baseline.as_deref().map(bench::Report::load).transpose()?;
+
+    let mut report = bench::Report::default();
+    for day in days {
+        let input = puzzle::read_input(day.input_path)?;
+        report.insert(day.day, (day.bench)(&input, runs)?);
+    }
+
+    report.print(baseline.as_ref());
+
+    if let Some(path) = save {
+        report.save(&path)?;
+    }
+
+    Ok(())
+}
+
+fn generate(day: u8, size: usize, seed: u64) -> Result<(), Error> {
+    let day = days::get(day)?;
+    if size > day.max_size {
+        eprintln!("Warning: day {} inputs are capped to size {}", day.day, day.max_size);
+    }
+    println!("{}", (day.generate)(size, seed));
+
+    Ok(())
+}
+
+fn verify(day: Option<u8>, answers_path: Option<String>, record: bool) -> Result<(), Error> {
+    let days = match day {
+        Some(day) => vec![days::get(day)?],
+        None => days::DAYS.iter().collect(),
+    };
+
+    let answers_path = answers_path.as_deref().unwrap_or(answers::ANSWERS_PATH);
+    let mut answers = answers::Answers::load(answers_path)?;
+
+    let mut mismatches = 0;
+    for day in days {
+        let input = puzzle::read_input(day.input_path)?;
+
+        for part in days::PARTS {
+            let answer = (day.solve)(&input, part)?;
+
+            let status = match answers.get(day.day, part) {
+                _ if record => "recorded".to_string(),
+                Some(expected) if *expected == answer => "ok".to_string(),
+                Some(expected) => {
+                    mismatches += 1;
+                    format!("MISMATCH, expected {}", expected)
+                }
+                None => "no known answer".to_string(),
+            };
+            println!("Day {}, part {}: {} {}", day.day, part, answer, status);
+
+            if record {
+                answers.insert(day.day, part, answer);
+            }
+        }
+    }
+
+    if record {
+        answers.save(answers_path)?;
+    }
+
+    if mismatches > 0 {
+        return Err(anyhow!("{} answer(s) do not match", mismatches));
+    }
+
+    Ok(())
+}
+
+fn main() -> Result<(), Error> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+        } => run(day, part, input, format),
+        Command::Bench {
+            day,
+            runs,
+            baseline,
+            save,
+        } => bench(day, runs, baseline, save),
+        Command::Generate { day, size, seed } => generate(day, size, seed),
+        Command::Verify {
+            day,
+            answers,
+            record,
+        } => verify(day, answers, record),
+    }
+}
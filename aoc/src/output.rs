@@ -0,0 +1,79 @@
+use std::time::Duration;
+
+use clap::ValueEnum;
+use puzzle::Answer;
+use serde_json::{json, Value};
+
+/// How the answers are printed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// One human readable line per part
+    #[default]
+    Text,
+    /// One JSON object per line and per part
+    Json,
+}
+
+/// The answer to one part of a day, and the time it took to get it
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    /// Parsing and solving time
+    pub elapsed: Duration,
+}
+
+/// Numbers stay numbers in JSON, unless they are too big for it
+fn answer_to_json(answer: &Answer) -> Value {
+    match answer {
+        Answer::Number(n) => serde_json::Number::from_i128(*n)
+            .map(Value::Number)
+            .unwrap_or_else(|| Value::String(n.to_string())),
+        Answer::Text(t) => Value::String(t.clone()),
+    }
+}
+
+impl Record {
+    pub fn to_json(&self) -> Value {
+        json!({
+            "day": self.day,
+            "part": self.part,
+            "answer": answer_to_json(&self.answer),
+            "elapsed_ns": self.elapsed.as_nanos() as u64,
+        })
+    }
+
+    pub fn format(&self, format: Format) -> String {
+        match format {
+            Format::Text => format!("Day {}, part {}: {}", self.day, self.part, self.answer),
+            Format::Json => self.to_json().to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_record() {
+        let record = Record {
+            day: 3,
+            part: 1,
+            answer: Answer::Number(4361),
+            elapsed: Duration::from_nanos(1234),
+        };
+
+        assert_eq!(
+            record.format(Format::Json),
+            r#"{"day":3,"part":1,"answer":4361,"elapsed_ns":1234}"#
+        );
+        assert_eq!(record.format(Format::Text), "Day 3, part 1: 4361");
+    }
+
+    #[test]
+    fn test_json_answers() {
+        assert_eq!(answer_to_json(&Answer::Text("a\"b".to_string())).to_string(), r#""a\"b""#);
+        assert_eq!(answer_to_json(&Answer::Number(-2)).to_string(), "-2");
+    }
+}
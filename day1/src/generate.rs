@@ -0,0 +1,35 @@
+use puzzle::Generator;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::Document;
+
+static WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// One calibration line of letters, digits and spelled digits, with at least one real digit
+fn line(rng: &mut StdRng) -> String {
+    let mut chunks: Vec<String> = (0..rng.gen_range(1..8))
+        .map(|_| match rng.gen_range(0..3) {
+            0 => (0..rng.gen_range(1..6))
+                .map(|_| rng.gen_range('a'..='z'))
+                .collect(),
+            1 => WORDS.choose(rng).unwrap().to_string(),
+            _ => rng.gen_range(1..=9).to_string(),
+        })
+        .collect();
+
+    if !chunks.iter().any(|chunk| chunk.chars().any(|c| c.is_ascii_digit())) {
+        let at = rng.gen_range(0..=chunks.len());
+        chunks.insert(at, rng.gen_range(1..=9).to_string());
+    }
+
+    chunks.concat()
+}
+
+/// `size` is the number of lines
+impl Generator for Document {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        (0..size).map(|_| line(rng)).collect::<Vec<_>>().join("\n")
+    }
+}
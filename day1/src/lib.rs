@@ -0,0 +1,131 @@
+
+use anyhow::Error;
+use puzzle::{Answer, ParseError, Solver};
+
+mod generate;
+
+fn day1<'a>(input_lines: impl Iterator<Item = &'a str>) -> Result<u64, Error> {
+
+    input_lines.enumerate().map(|(i, line)| {
+        let mut digits = line.chars().filter_map(|c| c.to_digit(10));
+        let mut n = digits.next().ok_or_else(|| ParseError::new(i + 1, 1, line, "No digit in line"))?;
+        if let Some(m) = digits.next_back() {
+            n = n*10 + m;
+        } else {
+            n = n*10 + n
+        }
+        Ok(n as u64)
+    }).sum()
+}
+
+static DIGITS_TXT : [&str; 20]= [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9"
+];
+
+/// Length in bytes of the first character of a non empty line
+fn first_len(line: &str) -> usize {
+    line.chars().next().map_or(1, char::len_utf8)
+}
+
+fn day1_2<'a>(input_lines: impl Iterator<Item = &'a str>) -> Result<u64, Error> {
+
+    input_lines.enumerate().map(|(i, mut line)| {
+        let original = line;
+        let mut digits = Vec::new();
+        while !line.is_empty() {
+            let mut found = false;
+            for (i, d) in DIGITS_TXT.iter().enumerate() {
+                if line.to_lowercase().starts_with(d) {
+                    digits.push(i % 10);
+                    line = &line[first_len(line)..];
+                    found = true;
+                    break;
+                }
+            }
+            if !found {
+                line = &line[first_len(line)..];
+            }
+        }
+
+        let mut n = digits
+            .first()
+            .cloned()
+            .ok_or_else(|| ParseError::new(i + 1, 1, original, "No digit in line"))?;
+        if let Some(m) = digits.last().cloned() {
+            n = n*10 + m;
+        } else {
+            n = n*10 + n
+        }
+
+        Ok(n as u64)
+    }).sum()
+}
+
+/// Default puzzle input
+pub static INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+/// Calibration document, one calibration value per line
+pub struct Document {
+    lines: Vec<String>,
+}
+
+impl Solver for Document {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let lines = input.lines().map(str::to_string).collect();
+
+        Ok(Self { lines })
+    }
+
+    fn part1(&self) -> Result<Answer, Error> {
+        Ok(day1(self.lines.iter().map(String::as_str))?.into())
+    }
+
+    fn part2(&self) -> Result<Answer, Error> {
+        Ok(day1_2(self.lines.iter().map(String::as_str))?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part1_example() {
+        let example = r#"1abc2
+        pqr3stu8vwx
+        a1b2c3d4e5f
+        treb7uchet"#;
+        let expected = 142;
+
+        let current = day1(example.lines()).unwrap();
+
+        assert_eq!(expected, current);
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let example = r#"two1nine
+        eightwothree
+        abcone2threexyz
+        xtwone3four
+        4nineeightseven2
+        zoneight234
+        7pqrstsixteen"#;
+        let expected = 281;
+
+        let current = day1_2(example.lines()).unwrap();
+
+        assert_eq!(expected, current);
+    }
+
+    #[test]
+    fn test_no_digit_error_position() {
+        let error = day1("1abc2\nabc".lines()).err().unwrap();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((error.line, error.column, error.snippet.as_str()), (2, 1, "abc"));
+
+        let error = day1_2("éone\nàb\nx".lines()).err().unwrap();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((error.line, error.snippet.as_str()), (2, "àb"));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,112 @@
+use std::collections::HashSet;
+
+use grid::{Coordinate, Grid, Vec2d};
+use itertools::Itertools;
+use puzzle::Generator;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::Map;
+
+static JUNK: [char; 7] = ['|', '-', 'L', 'J', '7', 'F', '.'];
+
+/// Cells around the centre of a 3x3 block, in order
+static RING: [(i64, i64); 8] = [(0, 0), (1, 0), (2, 0), (2, 1), (2, 2), (1, 2), (0, 2), (0, 1)];
+
+/// A link between two cells, smallest first
+fn link(a: Coordinate, b: Coordinate) -> (Coordinate, Coordinate) {
+    (a.min(b), a.max(b))
+}
+
+/// Random tree of connected cells of a `size` by `size` grid, as its edges
+fn random_tree(rng: &mut StdRng, size: usize) -> (Grid<bool>, Vec<(Coordinate, Coordinate)>) {
+    let mut in_tree = Grid::new(size, size, false);
+    let target = rng.gen_range((size * size / 3).max(1)..=size * size);
+
+    let root = Coordinate::new(rng.gen_range(0..size as i64), rng.gen_range(0..size as i64));
+    in_tree[root] = true;
+    let mut frontier: Vec<_> = in_tree.neighbours4(root).map(|n| (root, n)).collect();
+    let mut edges = Vec::new();
+
+    while edges.len() + 1 < target && !frontier.is_empty() {
+        let (from, to) = frontier.swap_remove(rng.gen_range(0..frontier.len()));
+        if in_tree[to] {
+            continue;
+        }
+        in_tree[to] = true;
+        edges.push(link(from, to));
+        frontier.extend(in_tree.neighbours4(to).filter(|n| !in_tree[*n]).map(|n| (to, n)));
+    }
+
+    (in_tree, edges)
+}
+
+/// Pipe tile linking a cell to its two neighbours in the directions `d1` and `d2`
+fn tile(d1: Vec2d, d2: Vec2d) -> char {
+    let has = |d| d1 == d || d2 == d;
+    match (has(Vec2d::UP), has(Vec2d::DOWN), has(Vec2d::LEFT), has(Vec2d::RIGHT)) {
+        (true, true, _, _) => '|',
+        (_, _, true, true) => '-',
+        (true, _, _, true) => 'L',
+        (true, _, true, _) => 'J',
+        (_, true, true, _) => '7',
+        _ => 'F',
+    }
+}
+
+/// `size` is the side of the grid the loop is drawn on, the map is `3 * size + 2` wide.
+///
+/// Each cell of a random tree becomes a ring of pipes around a 3x3 block of the map,
+/// the rings of connected cells are merged into a single loop around the tree. The centre
+/// of each block is enclosed, the cells left out of the tree are not, as the loop squeezes
+/// between the blocks. Junk pipes fill all the cells not on the loop.
+impl Generator for Map {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        let size = size.max(1);
+        let (in_tree, edges) = random_tree(rng, size);
+
+        // Top left cell of the block of a tree cell, the map has a margin of one tile
+        let block = |c: Coordinate| Coordinate::new(3 * c.x + 1, 3 * c.y + 1);
+        let (right, down) = (Vec2d::RIGHT, Vec2d::DOWN);
+
+        let mut links = HashSet::new();
+        for (c, _) in in_tree.iter().filter(|(_, in_tree)| **in_tree) {
+            let ring = RING.map(|(x, y)| block(c) + Vec2d::new(x, y));
+            links.extend(ring.iter().circular_tuple_windows().map(|(a, b)| link(*a, *b)));
+        }
+        // Open the facing sides of two connected rings and link them together
+        for (a, b) in edges {
+            let (across, side) = if b.x > a.x { (right, down) } else { (down, right) };
+            let (a, b) = (block(a) + across + across, block(b));
+            links.remove(&link(a, a + side));
+            links.remove(&link(b, b + side));
+            links.insert(link(a, b));
+            links.insert(link(a + side, b + side));
+        }
+
+        let width = 3 * size + 2;
+        let mut map = Grid::new(width, width, '.');
+        let mut neighbours: Grid<Vec<Coordinate>> = Grid::new(width, width, Vec::new());
+        for (a, b) in &links {
+            neighbours[*a].push(*b);
+            neighbours[*b].push(*a);
+        }
+        for position in map.coordinates().collect::<Vec<_>>() {
+            map[position] = match neighbours[position].as_slice() {
+                [n1, n2] => tile(*n1 - position, *n2 - position),
+                _ => *JUNK.choose(rng).unwrap(),
+            };
+        }
+
+        // Nothing but the loop may link to the start
+        let on_loop: Vec<Coordinate> = map.coordinates().filter(|p| neighbours[*p].len() == 2).collect();
+        let start = *on_loop.choose(rng).unwrap();
+        map[start] = 'S';
+        for n in map.neighbours4(start).collect::<Vec<_>>() {
+            if neighbours[n].len() != 2 {
+                map[n] = '.';
+            }
+        }
+
+        map.to_string().trim_end().to_string()
+    }
+}
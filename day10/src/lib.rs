@@ -0,0 +1,302 @@
+use std::{collections::VecDeque, str::FromStr};
+
+use anyhow::{anyhow, Error};
+use grid::{Coordinate, Grid, Vec2d};
+use itertools::Itertools;
+use puzzle::{Answer, ParseError, Solver};
+
+mod generate;
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Property {
+    #[default]
+    Unknown,
+    Track,
+    Left,
+    Right,
+}
+
+#[derive(Clone)]
+pub struct Map {
+    /// The two cells each pipe connects
+    pipes: Grid<Option<(Coordinate, Coordinate)>>,
+    start: Coordinate,
+    metadata: Grid<Property>,
+    /// Side of the loop found to touch the outside of the map while walking it
+    outside: Option<Property>,
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut pipes = Grid::parse(s, |position, tile| {
+            let directions = match tile {
+                '.' => return Ok(None),
+                '-' => (position + Vec2d::LEFT, position + Vec2d::RIGHT),
+                '|' => (position + Vec2d::UP, position + Vec2d::DOWN),
+                'L' => (position + Vec2d::UP, position + Vec2d::RIGHT),
+                'J' => (position + Vec2d::UP, position + Vec2d::LEFT),
+                '7' => (position + Vec2d::LEFT, position + Vec2d::DOWN),
+                'F' => (position + Vec2d::RIGHT, position + Vec2d::DOWN),
+                // Start is a special case, we do not know (yet) it's direction,
+                // it points to itself for now, solve it later down
+                'S' => (position, position),
+
+                _ => return Err(anyhow!("Bad format, tile '{}' unknown", tile)),
+            };
+
+            Ok(Some(directions))
+        })?;
+
+        let start = pipes
+            .iter()
+            .find(|(position, pipe)| **pipe == Some((*position, *position)))
+            .map(|(position, _)| position)
+            .ok_or_else(|| ParseError::at_end(s, "No start tile 'S' found"))?;
+
+        // Resolve start directions
+        let directions = pipes
+            .neighbours4(start)
+            .filter(|neighbour| {
+                matches!(pipes[*neighbour], Some((n1, n2)) if n1 == start || n2 == start)
+            })
+            .collect_tuple()
+            .ok_or_else(|| {
+                ParseError::new(
+                    start.y as usize + 1,
+                    start.x as usize + 1,
+                    "S",
+                    "2 tiles should link to start",
+                )
+            })?;
+
+        pipes[start] = Some(directions);
+
+        let metadata = Grid::new(pipes.width(), pipes.height(), Property::default());
+
+        Ok(Self { pipes, start, metadata, outside: None })
+    }
+}
+
+impl Map {
+    /// Set the property of a cell that does not have one yet
+    fn mark(&mut self, position: Coordinate, property: Property) {
+        match self.metadata.get_mut(position) {
+            Some(cell) if *cell == Property::Unknown => *cell = property,
+            Some(_) => (),
+            None => self.outside = Some(property),
+        }
+    }
+
+    pub fn loop_length(&mut self) -> Result<u32, Error> {
+        let mut len = 1;
+        let mut visited = Grid::new(self.pipes.width(), self.pipes.height(), false);
+
+        let mut prev_pos = self.start;
+        let (mut current_pos, _) = self.pipes[self.start].ok_or(anyhow!("No pipe at start"))?;
+        visited[self.start] = true;
+        self.metadata[self.start] = Property::Track;
+
+        while current_pos != self.start {
+            let (n1, n2) = self
+                .pipes
+                .get(current_pos)
+                .cloned()
+                .flatten()
+                .ok_or(anyhow!("Map error, {:?} not found", current_pos))?;
+
+            visited[current_pos] = true;
+            self.metadata[current_pos] = Property::Track;
+
+            let next_pos = if !visited.get(n1).cloned().unwrap_or_default() || (n1 == self.start && len > 1) {
+                n1
+            } else if !visited.get(n2).cloned().unwrap_or_default() || (n2 == self.start && len > 1) {
+                n2
+            } else {
+                return Err(anyhow!("Walk error, nowhere to go ..."));
+            };
+
+            // Calculate and set left/right properties for prev->current and current->next pos vector.
+            for vec in [current_pos - prev_pos, next_pos - current_pos] {
+                let left = current_pos + vec.rotate_90();
+                let right = current_pos - vec.rotate_90();
+
+                self.mark(left, Property::Left);
+                self.mark(right, Property::Right);
+            }
+
+            prev_pos = current_pos;
+            current_pos = next_pos;
+            len += 1;
+        }
+
+        // Finish to fill properties for all cells, expand LEFT/RIGHT cells to neighbors
+        let mut queue: VecDeque<Coordinate> = self
+            .metadata
+            .iter()
+            .filter(|(_, property)| matches!(property, Property::Left | Property::Right))
+            .map(|(position, _)| position)
+            .collect();
+
+        while let Some(position) = queue.pop_front() {
+            let property = self.metadata[position];
+            let neighbours = self.metadata.neighbours4(position).collect_vec();
+            for neighbour in neighbours {
+                if self.metadata[neighbour] == Property::Unknown {
+                    self.metadata[neighbour] = property;
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+
+        Ok(len)
+    }
+
+    pub fn count(&self, property: Property) -> usize {
+        self.metadata.iter().filter(|(_, p)| **p == property).count()
+    }
+
+    /// Number of cells enclosed by the loop, the side that never touches the border of the map
+    pub fn enclosed_cells(&self) -> usize {
+        let outside = self.outside.or_else(|| {
+            self.metadata
+                .iter()
+                .find(|(position, property)| {
+                    matches!(property, Property::Left | Property::Right)
+                        && self.metadata.is_border(*position)
+                })
+                .map(|(_, property)| *property)
+        });
+
+        if outside == Some(Property::Left) {
+            self.count(Property::Right)
+        } else {
+            self.count(Property::Left)
+        }
+    }
+
+    pub fn print_pipes(&self) {
+        let tiles = self.pipes.render(|position, pipe| {
+            let (n1, n2) = pipe.unwrap_or((position, position));
+            let (d1, d2) = (n1 - position, n2 - position);
+
+            let color = match self.metadata[position] {
+                Property::Unknown => "",
+                Property::Left => "\x1B[30;32m",
+                Property::Right => "\x1B[30;31m",
+                Property::Track => "\x1B[30;34m",
+            };
+
+            let tile = match ((d1.x, d1.y), (d2.x, d2.y)) {
+                ((-1, 0), (1, 0)) | ((1, 0), (-1, 0)) => "─",
+                ((0, 1), (0, -1)) | ((0, -1), (0, 1)) => "│",
+                ((-1, 0), (0, -1)) | ((0, -1), (-1, 0)) => "┘",
+                ((1, 0), (0, -1)) | ((0, -1), (1, 0)) => "└",
+                ((1, 0), (0, 1)) | ((0, 1), (1, 0)) => "┌",
+                ((-1, 0), (0, 1)) | ((0, 1), (-1, 0)) => "┐",
+
+                _ => "╳",
+            };
+
+            format!("{}{}\x1B[0m", color, tile)
+        });
+
+        print!("   ");
+        for x in 0..self.pipes.width() {
+            print!("{}", x%10);
+        }
+        println!();
+
+        for (y, line) in tiles.lines().enumerate() {
+            println!("{:2} {}", y%100, line);
+        }
+    }
+}
+
+/// Default puzzle input
+pub static INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+impl Solver for Map {
+    fn parse(input: &str) -> Result<Self, Error> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> Result<Answer, Error> {
+        // Walking the loop fills the map metadata, work on a copy
+        let mut map = self.clone();
+        Ok((map.loop_length()? / 2).into())
+    }
+
+    fn part2(&self) -> Result<Answer, Error> {
+        let mut map = self.clone();
+        map.loop_length()?;
+        Ok(map.enclosed_cells().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static SIMPLE_EXAMPLE: &str = r#"-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF"#;
+
+    static COMPLEX_EXAMPLE: &str = r#"..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ..."#;
+
+static PART2_EXAMPLE: &str = r#"..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+.........."#;
+
+    #[test]
+    fn test_simple_example() -> Result<(), Error> {
+        let mut map = Map::from_str(SIMPLE_EXAMPLE)?;
+        let expected = 4;
+
+        let actual = map.loop_length()? / 2;
+
+        map.print_pipes();
+
+        assert_eq!(expected, actual);
+        Ok(())
+    }
+
+    #[test]
+    fn test_complex_example() -> Result<(), Error> {
+        let mut map = Map::from_str(COMPLEX_EXAMPLE)?;
+        let expected = 8;
+
+        let actual = map.loop_length()? / 2;
+
+        map.print_pipes();
+
+        assert_eq!(expected, actual);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2_example() -> Result<(), Error> {
+        let mut map = Map::from_str(PART2_EXAMPLE)?;
+        let expected = 4;
+
+        map.loop_length()?;
+
+        map.print_pipes();
+
+        assert_eq!(expected, map.enclosed_cells());
+        Ok(())
+    }
+}
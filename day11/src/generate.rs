@@ -0,0 +1,27 @@
+use puzzle::Generator;
+use rand::{rngs::StdRng, Rng};
+
+use crate::Image;
+
+/// `size` is the side of the square image, about a tenth of the rows and columns are empty
+impl Generator for Image {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        let empty_rows: Vec<bool> = (0..size).map(|_| rng.gen_bool(0.1)).collect();
+        let empty_columns: Vec<bool> = (0..size).map(|_| rng.gen_bool(0.1)).collect();
+
+        (0..size)
+            .map(|y| {
+                (0..size)
+                    .map(|x| {
+                        if !empty_rows[y] && !empty_columns[x] && rng.gen_bool(0.05) {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
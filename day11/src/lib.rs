@@ -0,0 +1,219 @@
+use std::str::FromStr;
+use anyhow::Error;
+use grid::{Coordinate, Grid};
+use itertools::Itertools;
+use rayon::prelude::*;
+use puzzle::{Answer, ParseError, Solver};
+
+mod generate;
+
+#[derive(Clone)]
+pub struct Image {
+    data: Vec<Coordinate>,
+    empty_rows: Vec<i64>,
+    empty_columns: Vec<i64>,
+}
+
+impl FromStr for Image {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::from_str(s)?;
+
+        let data = grid
+            .iter()
+            .filter(|(_, cell)| **cell == '#')
+            .map(|(position, _)| position)
+            .collect();
+
+        let empty_rows = grid
+            .rows()
+            .positions(|row| row.iter().all(|cell| *cell != '#'))
+            .map(|y| y as i64)
+            .collect();
+
+        let empty_columns = grid
+            .columns()
+            .positions(|mut column| column.all(|cell| *cell != '#'))
+            .map(|x| x as i64)
+            .collect();
+
+        Ok(Self{data, empty_rows, empty_columns})
+    }
+}
+
+impl Image {
+    fn expand_universe_with(&mut self, expansion: i64) {
+        for x in self.empty_columns.iter().rev() {
+            self.data.iter_mut().filter(|c| c.x > *x).for_each(|c| c.x += expansion);
+        }
+
+        for y in self.empty_rows.iter().rev() {
+            self.data.iter_mut().filter(|c| c.y > *y).for_each(|c| c.y += expansion);
+        }
+    }
+
+    fn sum_distances(&self) -> u64 {
+        self.data
+            .iter()
+            .tuple_combinations()
+            .par_bridge()
+            .map(|(a, b)| a.distance(*b))
+            .sum()
+    }
+}
+
+/// Default puzzle input
+pub static INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+impl Solver for Image {
+    fn parse(input: &str) -> Result<Self, Error> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> Result<Answer, Error> {
+        let mut image = self.clone();
+        image.expand_universe_with(1);
+        Ok(image.sum_distances().into())
+    }
+
+    fn part2(&self) -> Result<Answer, Error> {
+        let mut image = self.clone();
+        image.expand_universe_with(1000000 - 1);
+        Ok(image.sum_distances().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use puzzle::Generator;
+
+    use super::*;
+
+    /// Walk from `a` to `b` one step at a time
+    fn step_distance(a: Coordinate, b: Coordinate) -> u64 {
+        let mut position = a;
+        let mut steps = 0;
+        while position != b {
+            if position.x != b.x {
+                position.x += (b.x - position.x).signum();
+            } else {
+                position.y += (b.y - position.y).signum();
+            }
+            steps += 1;
+        }
+        steps
+    }
+
+    /// Galaxies of the image once each empty row and column is really inserted `expansion` more times
+    fn expand_naively(input: &str, expansion: usize) -> Vec<Coordinate> {
+        let rows: Vec<Vec<char>> = input
+            .lines()
+            .flat_map(|line| {
+                let copies = if line.contains('#') { 1 } else { expansion + 1 };
+                std::iter::repeat_n(line.chars().collect(), copies)
+            })
+            .collect();
+
+        let empty_columns: Vec<bool> = (0..rows[0].len())
+            .map(|x| rows.iter().all(|row| row[x] != '#'))
+            .collect();
+
+        rows.iter()
+            .map(|row| {
+                row.iter()
+                    .zip(&empty_columns)
+                    .flat_map(|(c, empty)| {
+                        std::iter::repeat_n(*c, if *empty { expansion + 1 } else { 1 })
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.into_iter()
+                    .positions(|c| c == '#')
+                    .map(move |x| Coordinate::new(x as i64, y as i64))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    static EXAMPLE: &str = include_str!("../example.txt");
+    
+    #[test]
+    fn test_coordinate_distance() -> Result<(), Error>{
+        let mut image = Image::from_str(EXAMPLE)?;
+        image.expand_universe_with(1);
+        let expected = 5;
+
+        let actual = image.data[7].distance(image.data[8]);
+
+        assert_eq!(expected, actual);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part1() -> Result<(), Error> {
+        let mut image = Image::from_str(EXAMPLE)?;
+        image.expand_universe_with(1);
+        let expected = 374;
+
+        let actual = image.sum_distances();
+
+        assert_eq!(expected, actual);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2_10() -> Result<(), Error> {
+        let mut image = Image::from_str(EXAMPLE)?;
+        image.expand_universe_with(9);
+        let expected = 1030;
+
+        let actual = image.sum_distances();
+
+        assert_eq!(expected, actual);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2_100() -> Result<(), Error> {
+        let mut image = Image::from_str(EXAMPLE)?;
+        image.expand_universe_with(99);
+        let expected = 8410;
+
+        let actual = image.sum_distances();
+
+        assert_eq!(expected, actual);
+
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn test_distance_matches_steps(ax in -100i64..100, ay in -100i64..100, bx in -100i64..100, by in -100i64..100) {
+            let (a, b) = (Coordinate::new(ax, ay), Coordinate::new(bx, by));
+
+            prop_assert_eq!(step_distance(a, b), a.distance(b));
+        }
+
+        #[test]
+        fn test_sum_distances_matches_naive(seed: u64, size in 1usize..25, expansion in 0usize..4) {
+            let input = Image::generate(size, seed);
+            let expected: u64 = expand_naively(&input, expansion)
+                .iter()
+                .tuple_combinations()
+                .map(|(a, b)| step_distance(*a, *b))
+                .sum();
+
+            let mut image = Image::from_str(&input)?;
+            image.expand_universe_with(expansion as i64);
+
+            prop_assert_eq!(expected, image.sum_distances());
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use puzzle::Generator;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::Games;
+
+static COLORS: [&str; 3] = ["red", "green", "blue"];
+
+/// `size` is the number of games
+impl Generator for Games {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        (1..=size)
+            .map(|id| {
+                let reveals = (0..rng.gen_range(1..=6))
+                    .map(|_| {
+                        let n = rng.gen_range(1..=3);
+                        COLORS
+                            .choose_multiple(rng, n)
+                            .map(|color| format!("{} {}", rng.gen_range(1..=20), color))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    })
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                format!("Game {}: {}", id, reveals)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
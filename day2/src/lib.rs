@@ -0,0 +1,175 @@
+use anyhow::Error;
+use puzzle::{Answer, Locate, ParseError, Solver, Within};
+use std::str::FromStr;
+
+mod generate;
+
+#[derive(Debug)]
+struct RevealSet {
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+
+impl FromStr for RevealSet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut red = 0;
+        let mut green = 0;
+        let mut blue = 0;
+        for reveal in s.split(", ") {
+            let (number, color) = reveal
+                .split_once(' ')
+                .ok_or_else(|| ParseError::at(s, reveal, "Expected '<number> <color>'"))?;
+            let number = number.parse::<u32>().located(s, number)?;
+
+            match color {
+                "red" => red = number,
+                "green" => green = number,
+                "blue" => blue = number,
+                _ => return Err(ParseError::at(s, color, format!("Bad color: '{}'", color))),
+            }
+        }
+        Ok(Self { red, green, blue })
+    }
+}
+
+impl RevealSet {
+    fn evaluate(&self, red: u32, green: u32, blue: u32) -> bool {
+        (self.red) <= red && (self.green <= green) && (self.blue <= blue)
+    }
+}
+
+struct Game {
+    reveals: Vec<RevealSet>,
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let reveals: Result<Vec<RevealSet>, ParseError> = s
+            .split("; ")
+            .map(|reveal| RevealSet::from_str(reveal).within(s, reveal))
+            .collect();
+
+        Ok(Self { reveals: reveals? })
+    }
+}
+
+impl Game {
+    fn evaluate(&self, red: u32, green: u32, blue: u32) -> bool {
+        self.reveals
+            .iter()
+            .all(|reveal| reveal.evaluate(red, green, blue))
+    }
+
+    fn power(&self) -> u32 {
+        let max_red = self.reveals.iter().map(|r| r.red).max().unwrap_or(0);
+        let max_green = self.reveals.iter().map(|r| r.green).max().unwrap_or(0);
+        let max_blue = self.reveals.iter().map(|r| r.blue).max().unwrap_or(0);
+
+        max_red * max_green * max_blue
+    }
+}
+
+pub struct Games {
+    games: Vec<Game>,
+}
+
+impl FromStr for Games {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let games: Result<Vec<Game>, ParseError> = s
+            .lines()
+            .map(|line| {
+                let (_, game) = line
+                    .split_once(": ")
+                    .ok_or_else(|| ParseError::at(s, line, "Missing 'Game N: ' header"))?;
+                Game::from_str(game).within(s, game)
+            })
+            .collect();
+        Ok(Self { games: games? })
+    }
+}
+
+impl Games {
+    fn evaluate(&self, red: u32, green: u32, blue: u32) -> u32 {
+        self.games
+            .iter()
+            .enumerate()
+            .filter(|(_, g)| g.evaluate(red, green, blue))
+            .map(|(i, _)| (i + 1) as u32)
+            .sum()
+    }
+
+    fn power(&self) -> u32 {
+        self.games.iter().map(|g| g.power()).sum()
+    }
+}
+
+/// Default puzzle input
+pub static INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+impl Solver for Games {
+    fn parse(input: &str) -> Result<Self, Error> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> Result<Answer, Error> {
+        Ok(self.evaluate(12, 13, 14).into())
+    }
+
+    fn part2(&self) -> Result<Answer, Error> {
+        Ok(self.power().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part1_example() {
+        let input = r#"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+        Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+        Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+        Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+        Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"#;
+        let expected = 8;
+
+        let games = Games::from_str(input).unwrap();
+
+        let actual = games.evaluate(12, 13, 14);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let input = r#"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+        Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+        Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+        Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+        Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"#;
+        let expected = 2286;
+
+        let games = Games::from_str(input).unwrap();
+
+        let actual = games.power();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_error_position() {
+        let input = "Game 1: 3 blue, 4 red\nGame 2: 1 blue, x green; 3 red\nGame 3: 1 purple";
+
+        let error = Games::from_str(input).err().unwrap();
+        assert_eq!((2, 17, "x"), (error.line, error.column, error.snippet.as_str()));
+
+        let error = Games::from_str(&input.replace('x', "2")).err().unwrap();
+        assert_eq!((3, 11, "purple"), (error.line, error.column, error.snippet.as_str()));
+    }
+}
@@ -0,0 +1,35 @@
+use puzzle::Generator;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::Schematic;
+
+static SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '=', '%', '@', '&', '-'];
+
+/// `size` is the side of the square schematic
+impl Generator for Schematic {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let mut row = String::new();
+                while row.len() < size {
+                    let left = size - row.len();
+                    match rng.gen_range(0..20) {
+                        0 | 1 => {
+                            let len = rng.gen_range(1..=3).min(left);
+                            let number = rng.gen_range(10u32.pow(len as u32 - 1)..10u32.pow(len as u32));
+                            row.push_str(&number.to_string());
+                            // Numbers never touch each other on a row
+                            if row.len() < size {
+                                row.push('.');
+                            }
+                        }
+                        2 => row.push(*SYMBOLS.choose(rng).unwrap()),
+                        _ => row.push('.'),
+                    }
+                }
+                row
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
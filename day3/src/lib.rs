@@ -0,0 +1,169 @@
+use anyhow::Result;
+use grid::{Coordinate, Grid, Vec2d};
+use puzzle::{Answer, ParseError, Solver};
+use std::str::FromStr;
+
+mod generate;
+
+/// A number written horizontally, starting at `position`
+#[derive(Debug)]
+struct Number {
+    position: Coordinate,
+    len: i64,
+    value: u32,
+}
+
+impl Number {
+    fn cells(&self) -> impl Iterator<Item = Coordinate> + '_ {
+        (0..self.len).map(|dx| self.position + Vec2d::new(dx, 0))
+    }
+
+    /// Is the position touching the number, diagonals included
+    fn is_adjacent(&self, position: Coordinate) -> bool {
+        (self.position.x - 1..=self.position.x + self.len).contains(&position.x)
+            && (self.position.y - 1..=self.position.y + 1).contains(&position.y)
+    }
+}
+
+fn is_symbol(c: char) -> bool {
+    c != '.' && !c.is_ascii_digit()
+}
+
+#[derive(Debug)]
+pub struct Schematic {
+    grid: Grid<char>,
+    numbers: Vec<Number>,
+}
+
+impl FromStr for Schematic {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::from_str(s)?;
+
+        let mut numbers = Vec::new();
+        for (y, row) in grid.rows().enumerate() {
+            let mut x = 0;
+            while x < row.len() {
+                let len = row[x..].iter().take_while(|c| c.is_ascii_digit()).count();
+                if len > 0 {
+                    let digits: String = row[x..x + len].iter().collect();
+                    numbers.push(Number {
+                        position: Coordinate::new(x as i64, y as i64),
+                        len: len as i64,
+                        value: digits
+                            .parse()
+                            .map_err(|e| ParseError::new(y + 1, x + 1, &digits, e))?,
+                    });
+                    x += len;
+                } else {
+                    x += 1;
+                }
+            }
+        }
+
+        Ok(Self { grid, numbers })
+    }
+}
+
+impl Schematic {
+    fn sum_numbers(&self) -> u32 {
+        self.numbers
+            .iter()
+            .filter(|number| {
+                number.cells().any(|cell| {
+                    self.grid
+                        .neighbours8(cell)
+                        .any(|neighbour| is_symbol(self.grid[neighbour]))
+                })
+            })
+            .map(|number| number.value)
+            .sum()
+    }
+
+    fn gear_ratio(&self, position: Coordinate) -> u64 {
+        let numbers: Vec<u64> = self
+            .numbers
+            .iter()
+            .filter(|number| number.is_adjacent(position))
+            .map(|number| number.value as u64)
+            .collect();
+
+        if numbers.len() > 1 {
+            numbers.iter().product()
+        } else {
+            0
+        }
+    }
+
+    fn sum_gear_ratios(&self) -> u64 {
+        self.grid
+            .iter()
+            .filter(|(_, c)| **c == '*')
+            .map(|(position, _)| self.gear_ratio(position))
+            .sum()
+    }
+}
+
+/// Default puzzle input
+pub static INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+impl Solver for Schematic {
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.sum_numbers().into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.sum_gear_ratios().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part1_example() {
+        let input = r#"467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598.."#;
+        let schematic = Schematic::from_str(input).unwrap();
+
+        let expected = 4361;
+
+        let actual = schematic.sum_numbers();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let input = r#"467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598.."#;
+        let schematic = Schematic::from_str(input).unwrap();
+
+        let expected = 467835;
+
+        let actual = schematic.sum_gear_ratios();
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,55 @@
+use puzzle::Generator;
+use rand::{
+    rngs::StdRng,
+    seq::{index, SliceRandom},
+    Rng,
+};
+
+use crate::Cards;
+
+const WINNING: usize = 10;
+const HAVE: usize = 25;
+
+fn format_numbers(numbers: &[u32]) -> String {
+    numbers
+        .iter()
+        .map(|n| format!("{:>2}", n))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// `size` is the number of cards.
+///
+/// Cards win less than one card on average, and never past the end of the table,
+/// so the total number of cards stays reasonable.
+impl Generator for Cards {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        (1..=size)
+            .map(|id| {
+                let matches = if rng.gen_bool(0.65) {
+                    0
+                } else {
+                    rng.gen_range(1..=4).min(size - id)
+                };
+
+                // Draw all the numbers at once so they are distinct
+                let numbers: Vec<u32> = index::sample(rng, 99, WINNING + HAVE - matches)
+                    .iter()
+                    .map(|n| n as u32 + 1)
+                    .collect();
+                let winning = &numbers[..WINNING];
+                let mut have: Vec<u32> = numbers[WINNING..].to_vec();
+                have.extend(&winning[..matches]);
+                have.shuffle(rng);
+
+                format!(
+                    "Card {:>3}: {} | {}",
+                    id,
+                    format_numbers(winning),
+                    format_numbers(&have)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
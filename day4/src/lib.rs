@@ -0,0 +1,163 @@
+use std::str::FromStr;
+
+use anyhow::Result;
+use puzzle::{Answer, Locate, ParseError, Solver, Within};
+
+mod generate;
+
+struct Card {
+    _id: u32,
+    winning: Vec<u32>,
+    have: Vec<u32>,
+}
+
+/// Parse a space separated list of numbers, a slice of the card `s`
+fn numbers(s: &str, list: &str) -> Result<Vec<u32>, ParseError> {
+    list.split(' ')
+        .filter(|n| !n.is_empty())
+        .map(|n| n.parse().located(s, n))
+        .collect()
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (header, body) = s
+            .split_once(": ")
+            .ok_or_else(|| ParseError::at(s, s, "Bad format, expected 'Card N: ...'"))?;
+
+        let id = header.rsplit(' ').next().unwrap_or(header);
+        let id = id.parse::<u32>().located(s, id)?;
+
+        let (winning, have) = body
+            .split_once(" | ")
+            .ok_or_else(|| ParseError::at(s, body, "Bad body format, expected '<winning> | <have>'"))?;
+        let winning = numbers(s, winning)?;
+        let have = numbers(s, have)?;
+
+        Ok(Self {
+            _id: id,
+            winning,
+            have,
+        })
+    }
+}
+
+impl Card {
+    fn score(&self) -> u32 {
+        let mut score = 0;
+        for number in &self.have {
+            if self.winning.contains(number) {
+                if score == 0 {
+                    score = 1;
+                } else {
+                    score *= 2;
+                }
+            }
+        }
+        score
+    }
+
+    fn match_count(&self) -> usize {
+        self.have
+            .iter()
+            .filter(|n| self.winning.contains(n))
+            .count()
+    }
+}
+
+pub struct Cards {
+    cards: Vec<Card>,
+}
+
+impl FromStr for Cards {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cards = s
+            .lines()
+            .map(|line| Card::from_str(line).within(s, line))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { cards })
+    }
+}
+
+impl Cards {
+    fn score(&self) -> u32 {
+        self.cards.iter().map(|card| card.score()).sum()
+    }
+
+    fn process(&self) -> u32 {
+        let mut instances = vec![1; self.cards.len()];
+
+        for (i, card) in self.cards.iter().enumerate() {
+            let n_matches = card.match_count();
+            let n_instance = instances[i];
+            if n_matches > 0 {
+                for instance in &mut instances[(i + 1)..=(i + n_matches)] {
+                    *instance += n_instance;
+                }
+            }
+        }
+
+        instances.iter().sum()
+    }
+}
+
+/// Default puzzle input
+pub static INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+impl Solver for Cards {
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.score().into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.process().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    static EXAMPLE: &str = r#"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"#;
+
+    use anyhow::Result;
+
+    use super::*;
+    #[test]
+    fn test_part1_example() -> Result<()> {
+        let cards = Cards::from_str(EXAMPLE)?;
+
+        let expected_score = 13;
+
+        let actual_score = cards.score();
+
+        assert_eq!(expected_score, actual_score);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2_example() -> Result<()> {
+        let cards = Cards::from_str(EXAMPLE)?;
+
+        let expected = 30;
+
+        let actual = cards.process();
+
+        assert_eq!(expected, actual);
+
+        Ok(())
+    }
+}
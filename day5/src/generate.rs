@@ -0,0 +1,67 @@
+use puzzle::Generator;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::Almanac;
+
+static CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+/// Lines of a map moving around the ranges of `0..max`, without overlaps
+fn map_lines(rng: &mut StdRng, max: u64, ranges: usize) -> Vec<String> {
+    let mut cuts: Vec<u64> = (1..ranges).map(|_| rng.gen_range(1..max)).collect();
+    cuts.push(0);
+    cuts.push(max);
+    cuts.sort();
+    cuts.dedup();
+
+    let sources: Vec<(u64, u64)> = cuts.windows(2).map(|w| (w[0], w[1] - w[0])).collect();
+
+    // Destinations are the same ranges in another order, so the map is one to one
+    let mut order: Vec<usize> = (0..sources.len()).collect();
+    order.shuffle(rng);
+    let mut destination = 0;
+    let mut lines = Vec::new();
+    for i in order {
+        let (source, len) = sources[i];
+        // Ranges left out of the map are kept as is
+        if source != destination || rng.gen_bool(0.8) {
+            lines.push(format!("{} {} {}", destination, source, len));
+        }
+        destination += len;
+    }
+
+    lines.shuffle(rng);
+    lines
+}
+
+/// `size` is the number of ranges in each map, the values are below `1000 * size`
+impl Generator for Almanac {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        let size = size.max(1);
+        let max = 1000 * size as u64;
+
+        let seeds = (0..rng.gen_range(1..=10))
+            .map(|_| format!("{} {}", rng.gen_range(0..max), rng.gen_range(1..=max / 20)))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let maps = CATEGORIES.windows(2).map(|w| {
+            let mut lines = vec![format!("{}-to-{} map:", w[0], w[1])];
+            lines.extend(map_lines(rng, max, size));
+            lines.join("\n")
+        });
+
+        std::iter::once(format!("seeds: {}", seeds))
+            .chain(maps)
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
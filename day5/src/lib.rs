@@ -0,0 +1,735 @@
+use anyhow::{anyhow, Error};
+use std::ops::Range;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::str::FromStr;
+use itertools::Itertools;
+use puzzle::{Answer, Locate, ParseError, Solver, Within};
+
+mod generate;
+
+/// Default puzzle input
+pub static INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+/// Conversion of the numbers of a category to another
+#[derive(Debug)]
+pub struct Map {
+    source: String,
+    destination: String,
+    /// Source and destination ranges, sorted by source without overlaps
+    map: Vec<(Range<u64>, Range<u64>)>,
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+        let header = lines
+            .next()
+            .ok_or_else(|| ParseError::at_end(s, "Empty map"))?
+            .trim_end_matches(" map:");
+        let (source, destination) = header.split_once("-to-").ok_or_else(|| {
+            ParseError::at(s, header, "Map header should be '<source>-to-<destination> map:'")
+        })?;
+        let source = source.to_owned();
+        let destination = destination.to_owned();
+
+        let mut map = Vec::new();
+        for line in lines {
+            let content: Vec<u64> = line
+                .split(' ')
+                .map(|n| n.parse().located(s, n))
+                .collect::<Result<_, _>>()?;
+            if content.len() != 3 {
+                return Err(ParseError::at(
+                    s,
+                    line,
+                    "Map line should be '<destination start> <source start> <length>'",
+                ));
+            }
+            let (Some(source_end), Some(destination_end)) =
+                (content[1].checked_add(content[2]), content[0].checked_add(content[2]))
+            else {
+                return Err(ParseError::at(s, line, "Range goes beyond the largest value"));
+            };
+            let source_range = content[1]..source_end;
+            let destination_range = content[0]..destination_end;
+            if !source_range.is_empty() {
+                map.push((source_range, destination_range, line));
+            }
+        }
+
+        map.sort_by_key(|(source_range, _, _)| source_range.start);
+        if let Some(((_, _, line), (_, _, other))) = map
+            .iter()
+            .tuple_windows()
+            .find(|((a, _, _), (b, _, _))| a.end > b.start)
+        {
+            return Err(ParseError::at(
+                s,
+                other,
+                format!("Source range overlaps the one of line '{}'", line),
+            ));
+        }
+        let map = map
+            .into_iter()
+            .map(|(source_range, destination_range, _)| (source_range, destination_range))
+            .collect();
+
+        Ok(Self {
+            source,
+            destination,
+            map,
+        })
+    }
+}
+
+impl Map {
+    fn source(&self) -> String {
+        self.source.clone()
+    }
+
+    fn destination(&self) -> String {
+        self.destination.clone()
+    }
+
+    /// Index of the first range that ends after `source`
+    fn first_after(&self, source: u64) -> usize {
+        self.map.partition_point(|(source_range, _)| source_range.end <= source)
+    }
+
+    pub fn map(&self, source: u64) -> u64 {
+        match self.map.get(self.first_after(source)) {
+            Some((source_range, destination_range)) if source_range.contains(&source) => {
+                destination_range.start + (source - source_range.start)
+            }
+            _ => source,
+        }
+    }
+
+    /// Map a whole range of sources, split in the ranges of destinations it lands on
+    pub fn map_range(&self, range: Range<u64>) -> Vec<Range<u64>> {
+        let mut mapped = Vec::new();
+        let mut start = range.start;
+
+        for (source_range, destination_range) in &self.map[self.first_after(start)..] {
+            if source_range.start >= range.end {
+                break;
+            }
+
+            // Values before the source range are not mapped
+            if source_range.start > start {
+                mapped.push(start..source_range.start);
+                start = source_range.start;
+            }
+
+            let end = source_range.end.min(range.end);
+            let destination = destination_range.start + (start - source_range.start);
+            mapped.push(destination..destination + (end - start));
+            start = end;
+        }
+
+        if start < range.end {
+            mapped.push(start..range.end);
+        }
+
+        mapped
+    }
+
+    /// Sources below the end of the last range that no range maps
+    pub fn gaps(&self) -> Vec<Range<u64>> {
+        let mut gaps = Vec::new();
+        let mut start = 0;
+        for (source_range, _) in &self.map {
+            if source_range.start > start {
+                gaps.push(start..source_range.start);
+            }
+            start = source_range.end;
+        }
+
+        gaps
+    }
+
+    /// Ranges of destinations of all the sources, in the order of the sources
+    fn images(&self) -> Vec<Range<u64>> {
+        self.map_range(0..u64::MAX)
+    }
+
+    /// Map that does this map, then the `next` one
+    pub fn compose(&self, next: &Map) -> Map {
+        let mut map = Vec::new();
+        let mut start = 0;
+        for image in self.images() {
+            for destination in next.map_range(image) {
+                let len = destination.end - destination.start;
+                // Sources that end up unchanged need no entry
+                if destination.start != start {
+                    map.push((start..start + len, destination));
+                }
+                start += len;
+            }
+        }
+
+        Map {
+            source: self.source.clone(),
+            destination: next.destination.clone(),
+            map,
+        }
+    }
+
+    /// Sources that land in a range of destinations
+    pub fn preimage(&self, range: Range<u64>) -> Vec<Range<u64>> {
+        let mut sources = Vec::new();
+        let mut start = 0;
+        for image in self.images() {
+            let (low, high) = (image.start.max(range.start), image.end.min(range.end));
+            if low < high {
+                sources.push(start + (low - image.start)..start + (high - image.start));
+            }
+            start += image.end - image.start;
+        }
+
+        sources
+    }
+
+    /// Map going back from the destinations to the sources, if no two sources share a destination
+    pub fn invert(&self) -> Result<Map, Error> {
+        let mut start = 0;
+        let mut pieces: Vec<(Range<u64>, Range<u64>)> = self
+            .images()
+            .into_iter()
+            .map(|image| {
+                let source = start..start + (image.end - image.start);
+                start = source.end;
+                (image, source)
+            })
+            .collect();
+        pieces.sort_by_key(|(image, _)| image.start);
+
+        let overlap = pieces
+            .iter()
+            .tuple_windows()
+            .find(|((a, _), (b, _))| a.end > b.start);
+        if let Some(((a, _), (b, _))) = overlap {
+            return Err(anyhow!(
+                "Map {}-to-{} cannot be inverted, {:?} and {:?} overlap",
+                self.source,
+                self.destination,
+                a,
+                b
+            ));
+        }
+
+        Ok(Map {
+            source: self.destination.clone(),
+            destination: self.source.clone(),
+            map: pieces.into_iter().filter(|(image, source)| image != source).collect(),
+        })
+    }
+}
+
+/// The seeds and the maps between categories, a graph of categories linked by maps
+#[derive(Debug)]
+pub struct Almanac {
+    seeds: Vec<u64>,
+    /// Maps by source and destination categories
+    maps: BTreeMap<(String, String), Map>,
+}
+
+impl FromStr for Almanac {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut sections = s.split("\n\n");
+
+        let seeds_section = sections.next().unwrap_or_default();
+        let (_, seeds) = seeds_section
+            .split_once(": ")
+            .ok_or_else(|| ParseError::at(s, seeds_section, "Bad format, no seeds"))?;
+        let numbers: Vec<&str> = seeds.split(' ').collect();
+
+        // Part 2 reads the seeds as (start, length) pairs
+        let mut offset = 0;
+        for pair in numbers.chunks_exact(2) {
+            let end = offset + pair[0].len() + 1 + pair[1].len();
+            let start: u64 = pair[0].parse().located(s, pair[0])?;
+            let len: u64 = pair[1].parse().located(s, pair[1])?;
+            if start.checked_add(len).is_none() {
+                let message = "Seed range goes beyond the largest value";
+                return Err(ParseError::at(s, &seeds[offset..end], message));
+            }
+            offset = end + 1;
+        }
+
+        let seeds = numbers
+            .iter()
+            .map(|n| n.parse().located(s, n))
+            .collect::<Result<_, _>>()?;
+
+        let mut maps = BTreeMap::new();
+        for section in sections {
+            let map = Map::from_str(section).within(s, section)?;
+            // Each category converts to a single other one, so there is only one route
+            if maps.keys().any(|(source, _)| *source == map.source) {
+                let header = section.lines().next().unwrap_or_default();
+                let message = format!("Duplicate map from category '{}'", map.source);
+                return Err(ParseError::at(s, header, message));
+            }
+            maps.insert((map.source(), map.destination()), map);
+        }
+
+        Ok(Self { seeds, maps })
+    }
+}
+
+impl Almanac {
+    /// All the categories, sources or destinations of a map
+    pub fn categories(&self) -> BTreeSet<&str> {
+        self.maps
+            .keys()
+            .flat_map(|(source, destination)| [source.as_str(), destination.as_str()])
+            .collect()
+    }
+
+    fn maps_from<'a>(&'a self, source: &'a str) -> impl Iterator<Item = &'a Map> {
+        self.maps.values().filter(move |map| map.source == source)
+    }
+
+    /// Categories `source` can be converted to, nearest first
+    pub fn reachable<'a>(&'a self, source: &'a str) -> Vec<&'a str> {
+        let mut reached = vec![source];
+        let mut i = 0;
+        while let Some(category) = reached.get(i) {
+            let next: Vec<&str> = self
+                .maps_from(category)
+                .map(|map| map.destination.as_str())
+                .filter(|destination| !reached.contains(destination))
+                .collect();
+            reached.extend(next);
+            i += 1;
+        }
+
+        reached.remove(0);
+        reached
+    }
+
+    /// Shortest chain of maps from `source` to `destination`
+    fn path<'a>(&'a self, source: &'a str, destination: &str) -> Result<Vec<&'a Map>, Error> {
+        if !self.categories().contains(source) {
+            return Err(anyhow!("Unknown category '{}'", source));
+        }
+
+        let mut came_from: HashMap<&str, &Map> = HashMap::new();
+        let mut queue = VecDeque::from([source]);
+        while let Some(category) = queue.pop_front() {
+            if category == destination {
+                let mut path = Vec::new();
+                let mut current = destination;
+                while current != source {
+                    let map = came_from[current];
+                    path.push(map);
+                    current = &map.source;
+                }
+                path.reverse();
+                return Ok(path);
+            }
+
+            for map in self.maps_from(category) {
+                if map.destination != source && !came_from.contains_key(map.destination.as_str()) {
+                    came_from.insert(&map.destination, map);
+                    queue.push_back(&map.destination);
+                }
+            }
+        }
+
+        let dead_ends = std::iter::once(source)
+            .chain(self.reachable(source))
+            .filter(|category| self.maps_from(category).next().is_none())
+            .join(", ");
+        Err(anyhow!(
+            "Category '{}' cannot be converted to '{}', no maps from: {}",
+            source,
+            destination,
+            dead_ends
+        ))
+    }
+
+    /// A chain of categories converted back to its start, if any
+    fn find_cycle(&self) -> Option<Vec<&str>> {
+        fn visit<'a>(
+            almanac: &'a Almanac,
+            category: &'a str,
+            stack: &mut Vec<&'a str>,
+            visited: &mut HashSet<&'a str>,
+        ) -> Option<Vec<&'a str>> {
+            if let Some(i) = stack.iter().position(|c| *c == category) {
+                let mut cycle = stack[i..].to_vec();
+                cycle.push(category);
+                return Some(cycle);
+            }
+            if !visited.insert(category) {
+                return None;
+            }
+
+            stack.push(category);
+            for map in almanac.maps_from(category) {
+                if let Some(cycle) = visit(almanac, &map.destination, stack, visited) {
+                    return Some(cycle);
+                }
+            }
+            stack.pop();
+
+            None
+        }
+
+        let mut visited = HashSet::new();
+        self.categories()
+            .into_iter()
+            .find_map(|category| visit(self, category, &mut Vec::new(), &mut visited))
+    }
+
+    /// Check seeds can be converted to locations, and no category is converted back to itself
+    pub fn validate(&self) -> Result<(), Error> {
+        if let Some(cycle) = self.find_cycle() {
+            return Err(anyhow!("Maps form a cycle: {}", cycle.join(" -> ")));
+        }
+        self.path("seed", "location")?;
+
+        Ok(())
+    }
+
+    /// Convert values of the `source` category to the `destination` one
+    pub fn walk_map(
+        &self,
+        source: impl AsRef<str>,
+        destination: impl AsRef<str>,
+        values: &[u64],
+    ) -> Result<Vec<u64>, Error> {
+        let path = self.path(source.as_ref(), destination.as_ref())?;
+
+        Ok(values
+            .iter()
+            .map(|value| path.iter().fold(*value, |value, map| map.map(value)))
+            .collect())
+    }
+
+    /// All the maps from `source` to `destination` composed in a single map
+    pub fn pipeline(
+        &self,
+        source: impl AsRef<str>,
+        destination: impl AsRef<str>,
+    ) -> Result<Map, Error> {
+        let identity = Map {
+            source: source.as_ref().to_string(),
+            destination: source.as_ref().to_string(),
+            map: Vec::new(),
+        };
+
+        Ok(self
+            .path(source.as_ref(), destination.as_ref())?
+            .into_iter()
+            .fold(identity, |pipeline, map| pipeline.compose(map)))
+    }
+
+    /// Sources no range maps, below the end of the last range, for each map
+    pub fn gaps(&self) -> Vec<(String, Vec<Range<u64>>)> {
+        self.maps
+            .values()
+            .map(|map| (format!("{}-to-{}", map.source, map.destination), map.gaps()))
+            .collect()
+    }
+
+    /// Ranges of seeds planted in a range of locations
+    pub fn seeds_landing_in(&self, locations: Range<u64>) -> Result<Vec<Range<u64>>, Error> {
+        Ok(self.pipeline("seed", "location")?.preimage(locations))
+    }
+
+    fn find_smallest_distance(&self) -> Result<u64, Error> {
+        self.walk_map("seed", "location", &self.seeds)?
+            .into_iter()
+            .min()
+            .ok_or(anyhow!("No seed"))
+    }
+
+    fn find_smallest_range_distance(&self) -> Result<u64, Error> {
+        let pipeline = self.pipeline("seed", "location")?;
+
+        self.seeds
+            .iter()
+            .tuples()
+            .flat_map(|(start, len)| pipeline.map_range(*start..(*start + *len)))
+            .filter(|r| !r.is_empty())
+            .map(|r| r.start)
+            .min()
+            .ok_or(anyhow!("No seed range"))
+    }
+}
+
+impl Solver for Almanac {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let almanac = Self::from_str(input)?;
+        almanac.validate()?;
+
+        Ok(almanac)
+    }
+
+    fn part1(&self) -> Result<Answer, Error> {
+        Ok(self.find_smallest_distance()?.into())
+    }
+
+    fn part2(&self) -> Result<Answer, Error> {
+        Ok(self.find_smallest_range_distance()?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use puzzle::Generator;
+
+    use super::*;
+
+    /// Walk each seed of the ranges through the maps, one by one
+    fn naive_smallest_range_distance(almanac: &Almanac) -> u64 {
+        almanac
+            .seeds
+            .iter()
+            .tuples()
+            .flat_map(|(start, len)| *start..*start + *len)
+            .map(|seed| almanac.walk_map("seed", "location", &[seed]).unwrap()[0])
+            .min()
+            .unwrap()
+    }
+
+    static EXAMPLE: &str = r#"seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4"#;
+
+    #[test]
+    fn test_part1() -> Result<(), Error> {
+        let almanac = Almanac::from_str(EXAMPLE)?;
+        let expected = 35;
+        let actual = almanac.find_smallest_distance()?;
+
+        assert_eq!(expected, actual);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_range() -> Result<(), Error> {
+        let map = Map::from_str("seed-to-soil map:\n50 98 2\n52 50 48")?;
+
+        assert_eq!(vec![10..20], map.map_range(10..20));
+        assert_eq!(vec![45..50, 52..55], map.map_range(45..53));
+        assert_eq!(vec![99..100, 50..52, 100..105], map.map_range(97..105));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pipeline() -> Result<(), Error> {
+        let almanac = Almanac::from_str(EXAMPLE)?;
+        let pipeline = almanac.pipeline("seed", "location")?;
+
+        let locations = [79, 14, 55, 13].map(|seed| pipeline.map(seed));
+        assert_eq!([82, 43, 86, 35], locations);
+
+        let inverse = pipeline.invert()?;
+        assert_eq!([79, 14, 55, 13], locations.map(|location| inverse.map(location)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seeds_landing_in() -> Result<(), Error> {
+        let almanac = Almanac::from_str(EXAMPLE)?;
+
+        let seeds = almanac.seeds_landing_in(46..47)?;
+
+        assert_eq!(vec![82..83], seeds);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invert_error() -> Result<(), Error> {
+        // 98 and 99 are mapped to 50 and 51, which are also mapped to themselves
+        let map = Map::from_str("seed-to-soil map:\n50 98 2")?;
+
+        assert!(map.invert().is_err());
+        assert_eq!(vec![50..52, 98..100], map.preimage(50..52));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_walk_map_any_categories() -> Result<(), Error> {
+        let almanac = Almanac::from_str(EXAMPLE)?;
+
+        assert_eq!(vec![81, 49], almanac.walk_map("soil", "water", &[81, 14])?);
+        assert_eq!(vec![79], almanac.walk_map("seed", "seed", &[79])?);
+        assert_eq!(
+            vec!["temperature", "humidity", "location"],
+            almanac.reachable("light")
+        );
+
+        let error = almanac.walk_map("water", "soil", &[1]).unwrap_err();
+        assert_eq!(
+            "Category 'water' cannot be converted to 'soil', no maps from: location",
+            error.to_string()
+        );
+        assert!(almanac.walk_map("stone", "soil", &[1]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate() -> Result<(), Error> {
+        Almanac::from_str(EXAMPLE)?.validate()?;
+
+        let missing = EXAMPLE.replace("water-to-light", "water-to-sunlight");
+        let error = Almanac::from_str(&missing)?.validate().unwrap_err();
+        assert_eq!(
+            "Category 'seed' cannot be converted to 'location', no maps from: sunlight",
+            error.to_string()
+        );
+
+        let cycle = EXAMPLE.replace("humidity-to-location", "humidity-to-water");
+        let error = Almanac::from_str(&cycle)?.validate().unwrap_err();
+        assert_eq!(
+            "Maps form a cycle: water -> light -> temperature -> humidity -> water",
+            error.to_string()
+        );
+
+        let duplicate = format!("{}\n\nsoil-to-fertilizer map:\n1 2 3", EXAMPLE);
+        let error = Almanac::from_str(&duplicate).unwrap_err();
+        assert_eq!((35, 1), (error.line, error.column));
+
+        let branch = format!("{}\n\nseed-to-water map:\n1 2 3", EXAMPLE);
+        let error = Almanac::from_str(&branch).unwrap_err();
+        assert_eq!(
+            (35, "Duplicate map from category 'seed'"),
+            (error.line, error.message.as_str())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_overlapping_ranges() {
+        let input = EXAMPLE.replace("0 15 37", "0 14 37");
+
+        let error = Almanac::from_str(&input).unwrap_err();
+
+        assert_eq!(
+            "line 8, column 1: Source range overlaps the one of line '39 0 15' at '0 14 37'",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_gaps() -> Result<(), Error> {
+        let almanac = Almanac::from_str(EXAMPLE)?;
+
+        let gaps: HashMap<String, Vec<Range<u64>>> = almanac.gaps().into_iter().collect();
+
+        assert_eq!(vec![0..50], gaps["seed-to-soil"]);
+        assert_eq!(vec![0..18], gaps["water-to-light"]);
+        assert!(gaps["soil-to-fertilizer"].is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error_position() {
+        let input = EXAMPLE.replace("57 7 4", "57 7 4 1");
+
+        let error = Almanac::from_str(&input).err().unwrap();
+
+        assert_eq!((16, 1, "57 7 4 1"), (error.line, error.column, error.snippet.as_str()));
+
+        let input = EXAMPLE.replace("57 7 4", "57 18446744073709551610 7");
+        let error = Almanac::from_str(&input).err().unwrap();
+        assert_eq!(
+            (16, "Range goes beyond the largest value"),
+            (error.line, error.message.as_str())
+        );
+
+        let input = EXAMPLE.replace("55 13", "18446744073709551610 10");
+        let error = Almanac::from_str(&input).err().unwrap();
+        assert_eq!(
+            (1, 14, "18446744073709551610 10", "Seed range goes beyond the largest value"),
+            (error.line, error.column, error.snippet.as_str(), error.message.as_str())
+        );
+    }
+
+    #[test]
+    fn test_part2() -> Result<(), Error> {
+        let almanac = Almanac::from_str(EXAMPLE)?;
+        let expected = 46;
+        let actual = almanac.find_smallest_range_distance()?;
+
+        assert_eq!(expected, actual);
+
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn test_pipeline_matches_walk(seed: u64, size in 1usize..20, value in 0u64..20000) {
+            let almanac = Almanac::from_str(&Almanac::generate(size, seed))?;
+            let pipeline = almanac.pipeline("seed", "location").unwrap();
+
+            let location = almanac.walk_map("seed", "location", &[value]).unwrap()[0];
+            prop_assert_eq!(location, pipeline.map(value));
+            // Generated maps are one to one
+            prop_assert_eq!(value, pipeline.invert().unwrap().map(location));
+        }
+    }
+
+    proptest! {
+        // The naive side walks every seed, keep it short
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn test_smallest_range_distance_matches_naive(seed: u64, size in 1usize..6) {
+            let almanac = Almanac::from_str(&Almanac::generate(size, seed))?;
+
+            prop_assert_eq!(
+                naive_smallest_range_distance(&almanac),
+                almanac.find_smallest_range_distance().unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use puzzle::Generator;
+use rand::{rngs::StdRng, Rng};
+
+use crate::Sheet;
+
+/// `size` is the number of races.
+///
+/// The merged race of part 2 grows by a factor of ~100 with each race.
+impl Generator for Sheet {
+    /// At most 2 digits per race, the merged race time stays below 10¹⁸, its square fits in a u128
+    const MAX_SIZE: usize = 9;
+
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        let races: Vec<(u64, u64)> = (0..size.clamp(1, Self::MAX_SIZE))
+            .map(|_| {
+                let time = rng.gen_range(7..100);
+                // The best distance is reached holding the button half of the time
+                let best = (time / 2) * (time - time / 2);
+                (time, rng.gen_range(1..best))
+            })
+            .collect();
+
+        let times: String = races.iter().map(|(time, _)| format!("{:>7}", time)).collect();
+        let distances: String = races
+            .iter()
+            .map(|(_, distance)| format!("{:>7}", distance))
+            .collect();
+
+        format!("Time:    {}\nDistance:{}", times, distances)
+    }
+}
@@ -0,0 +1,275 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, Error};
+use puzzle::{Answer, Locate, ParseError, Solver};
+
+mod generate;
+
+/// How the values of the sheet are read
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kerning {
+    /// "Time:  7  15   30" are 3 races
+    Spaced,
+    /// "Time:  7  15   30" is one race of 71530 ms, spaces are ignored
+    Merged,
+}
+
+/// The races in the order of the sheet, a time can appear more than once
+struct Races {
+    races: Vec<(u128, u128)>,
+}
+
+/// Parse the values of a line after its header, `line` is a slice of `s`
+fn values(s: &str, line: &str, kerning: Kerning) -> Result<Vec<u128>, ParseError> {
+    let (_, values) = line
+        .split_once(':')
+        .ok_or_else(|| ParseError::at(s, line, "No header in line"))?;
+
+    match kerning {
+        Kerning::Spaced => values
+            .split(' ')
+            .filter(|s| !s.is_empty())
+            .map(|n| n.parse().located(s, n))
+            .collect(),
+        Kerning::Merged => {
+            let digits = values.trim();
+            Ok(vec![digits.replace(' ', "").parse().located(s, digits)?])
+        }
+    }
+}
+
+impl Races {
+    fn parse(s: &str, kerning: Kerning) -> Result<Self, ParseError> {
+        let mut lines = s.lines();
+        let times = values(
+            s,
+            lines
+                .next()
+                .ok_or_else(|| ParseError::at_end(s, "not enough lines, missing times"))?,
+            kerning,
+        )?;
+        let distances_line = lines
+            .next()
+            .ok_or_else(|| ParseError::at_end(s, "not enough lines, missing distances"))?;
+        let distances = values(s, distances_line, kerning)?;
+
+        if times.len() != distances.len() {
+            return Err(ParseError::at(
+                s,
+                distances_line,
+                "Number of time and distance not matching",
+            ));
+        }
+
+        let races = times.into_iter().zip(distances).collect();
+
+        Ok(Races { races })
+    }
+}
+
+impl FromStr for Races {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, Kerning::Spaced)
+    }
+}
+
+/// Number of ways to hold the button for `push` ms, so `push * (time - push) > best`
+fn ways_to_win(time: u128, best: u128) -> Result<u128, Error> {
+    // The distance is above the best between the roots of push² - time * push + best,
+    // (time ± √(time² - 4 * best)) / 2
+    let square = time
+        .checked_mul(time)
+        .ok_or_else(|| anyhow!("Race of {} ms is too long", time))?;
+    let Some(discriminant) = best.checked_mul(4).and_then(|best| square.checked_sub(best)) else {
+        return Ok(0);
+    };
+
+    // The integer square root is rounded down, fix the first push beating the best if needed
+    let beats = |push: u128| push * (time - push) > best;
+    let mut first = (time - discriminant.isqrt()) / 2;
+    while first <= time / 2 && !beats(first) {
+        first += 1;
+    }
+    while first > 0 && beats(first - 1) {
+        first -= 1;
+    }
+
+    if first > time / 2 {
+        // Even the best push does not beat the record
+        Ok(0)
+    } else {
+        // Pushes are symmetric around time / 2
+        Ok(time - 2 * first + 1)
+    }
+}
+
+impl Races {
+    fn way_to_win(&self) -> Result<u128, Error> {
+        self.races.iter().try_fold(1u128, |product, (time, best)| {
+            product
+                .checked_mul(ways_to_win(*time, *best)?)
+                .ok_or_else(|| anyhow!("Too many ways to win"))
+        })
+    }
+}
+
+/// Default puzzle input
+pub static INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+/// The sheet of paper, read as many races and as one race with bad kerning.
+/// Only part 2 fails if the merged race is too long to be read.
+pub struct Sheet {
+    races: Races,
+    race: Result<Races, ParseError>,
+}
+
+impl Solver for Sheet {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let races = Races::from_str(input)?;
+        let race = Races::parse(input, Kerning::Merged);
+
+        Ok(Self { races, race })
+    }
+
+    fn part1(&self) -> Result<Answer, Error> {
+        Ok(Answer::Number(self.races.way_to_win()?.try_into()?))
+    }
+
+    fn part2(&self) -> Result<Answer, Error> {
+        let race = self.race.as_ref().map_err(Clone::clone)?;
+
+        Ok(Answer::Number(race.way_to_win()?.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// Try every time the button can be held
+    fn brute_force_ways(time: u128, best: u128) -> u128 {
+        (0..=time).filter(|push| push * (time - push) > best).count() as u128
+    }
+
+    static EXAMPLE: &str=r#"Time:      7  15   30
+Distance:  9  40  200"#;
+
+    #[test]
+    fn test_part1() -> Result<()>{
+        let races = Races::from_str(EXAMPLE)?;
+        let expected = 288;
+
+        let actual = races.way_to_win()?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()>{
+        let sheet = Sheet::parse(EXAMPLE)?;
+        let expected = Answer::from(71503u64);
+
+        let actual = sheet.part2()?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_same_input_read_both_ways() -> Result<()> {
+        let races = Races::from_str("Time: 7 7 30\nDistance: 9 9 200")?;
+        assert_eq!(vec![(7, 9), (7, 9), (30, 200)], races.races);
+        assert_eq!(4 * 4 * 9, races.way_to_win()?);
+
+        let race = Races::parse(EXAMPLE, Kerning::Merged)?;
+        assert_eq!(vec![(71530, 940200)], race.races);
+
+        let error = Races::parse("Time: 7 1x\nDistance: 9 4", Kerning::Merged).err().unwrap();
+        assert_eq!((error.line, error.column, error.snippet.as_str()), (1, 7, "7 1x"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merged_race_too_long() -> Result<()> {
+        let sheet = Sheet::parse(&format!("Time: {}\nDistance: {}", "10 ".repeat(20), "1 ".repeat(20)))?;
+
+        assert_eq!(Answer::from(9u64.pow(20)), sheet.part1()?);
+        let error = sheet.part2().err().unwrap().to_string();
+        assert!(error.starts_with("line 1, column 7:"), "{}", error);
+        assert!(error.contains("too large"), "{}", error);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_generated_size_is_capped() -> Result<()> {
+        use puzzle::Generator;
+
+        let sheet = Sheet::parse(&Sheet::generate(100, 0))?;
+
+        assert_eq!(Sheet::MAX_SIZE, sheet.races.races.len());
+        sheet.part2()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hold_range_edges() -> Result<()> {
+        // Holding for 0 or `time` ms never moves the boat, holding `time - 1` ms can win
+        assert_eq!(0, ways_to_win(0, 0)?);
+        assert_eq!(0, ways_to_win(1, 0)?);
+        assert_eq!(1, ways_to_win(2, 0)?);
+        assert_eq!(2, ways_to_win(3, 1)?);
+        assert_eq!(2, Races::from_str("Time: 0 3\nDistance: 0 1")?.races.len());
+        assert_eq!(0, Races::from_str("Time: 0 3\nDistance: 0 1")?.way_to_win()?);
+        assert_eq!(2, Races::from_str("Time: 3\nDistance: 1")?.way_to_win()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_huge_race() -> Result<()> {
+        // Holding the button 10⁹ ms exactly matches the record
+        let time = 10u128.pow(19);
+        let best = 10u128.pow(9) * (time - 10u128.pow(9));
+
+        assert_eq!(time - 2 * 10u128.pow(9) - 1, ways_to_win(time, best)?);
+        assert_eq!(0, ways_to_win(time, (time / 2) * (time / 2))?);
+        assert!(ways_to_win(u128::MAX, 1).is_err());
+
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn test_ways_to_win_matches_brute_force(time in 0u128..2000, best in 0u128..1_000_000) {
+            prop_assert_eq!(brute_force_ways(time, best), ways_to_win(time, best).unwrap());
+        }
+
+        #[test]
+        fn test_way_to_win_matches_brute_force(
+            races in prop::collection::vec((0u128..200, 0u128..10000), 1..5)
+        ) {
+            let sheet = |values: Vec<String>| values.join(" ");
+            let input = format!(
+                "Time: {}\nDistance: {}",
+                sheet(races.iter().map(|(time, _)| time.to_string()).collect()),
+                sheet(races.iter().map(|(_, best)| best.to_string()).collect()),
+            );
+            let expected: u128 = races.iter().map(|(time, best)| brute_force_ways(*time, *best)).product();
+
+            let actual = Races::from_str(&input)?.way_to_win().unwrap();
+
+            prop_assert_eq!(expected, actual);
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use anyhow::{anyhow, Error};
+use day7::{CamelCards, ReportFormat};
+use puzzle::Solver;
+
+/// Explain the ranking of every hand: report [--jokers] [--csv] [input]
+fn main() -> Result<(), Error> {
+    let mut jokers = false;
+    let mut format = ReportFormat::Table;
+    let mut path = day7::INPUT_PATH.to_string();
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--jokers" => jokers = true,
+            "--csv" => format = ReportFormat::Csv,
+            _ if arg.starts_with("--") => return Err(anyhow!("Unknown option '{}'", arg)),
+            _ => path = arg,
+        }
+    }
+
+    let input = puzzle::read_input(&path)?;
+    let game = CamelCards::parse(&input)?;
+    let game = if jokers { game.jokers() } else { game.standard() };
+
+    print!("{}", game.report(format));
+
+    Ok(())
+}
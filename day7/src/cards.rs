@@ -0,0 +1,156 @@
+use std::{cmp::Ordering, fmt::Display};
+
+use anyhow::{anyhow, Error};
+use puzzle::{Locate, ParseError};
+
+use crate::rules::Rules;
+
+/// A card face, in the usual order, how strong it is depends on the rules
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Card {
+    C2 = 0,
+    C3,
+    C4,
+    C5,
+    C6,
+    C7,
+    C8,
+    C9,
+    CT,
+    CJ,
+    CQ,
+    CK,
+    CA,
+}
+
+impl Card {
+    /// Every card, from 2 to ace
+    pub const ALL: [Card; 13] = [
+        Card::C2,
+        Card::C3,
+        Card::C4,
+        Card::C5,
+        Card::C6,
+        Card::C7,
+        Card::C8,
+        Card::C9,
+        Card::CT,
+        Card::CJ,
+        Card::CQ,
+        Card::CK,
+        Card::CA,
+    ];
+}
+
+impl TryFrom<char> for Card {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '2' => Ok(Self::C2),
+            '3' => Ok(Self::C3),
+            '4' => Ok(Self::C4),
+            '5' => Ok(Self::C5),
+            '6' => Ok(Self::C6),
+            '7' => Ok(Self::C7),
+            '8' => Ok(Self::C8),
+            '9' => Ok(Self::C9),
+            'T' => Ok(Self::CT),
+            'J' => Ok(Self::CJ),
+            'Q' => Ok(Self::CQ),
+            'K' => Ok(Self::CK),
+            'A' => Ok(Self::CA),
+            _ => Err(anyhow!("Unknown card: '{}'", value)),
+        }
+    }
+}
+
+impl From<Card> for char {
+    fn from(card: Card) -> Self {
+        "23456789TJQKA".as_bytes()[card as usize] as char
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HandType {
+    FiveOfAKind = 0,
+    FourOfAKind,
+    FullHouse,
+    ThreeOfAKind,
+    TwoPair,
+    OnePair,
+    HighCard,
+}
+
+/// Five cards, classified and keyed by the rules they are played with
+#[derive(Clone, Debug)]
+pub struct Hand {
+    pub cards: [Card; 5],
+    hand_type: HandType,
+    /// The card the wildcards of the hand stand for, if it has any
+    wild_as: Option<Card>,
+    /// Strength of the cards in the order they break ties
+    key: [u8; 5],
+}
+
+impl Hand {
+    pub fn new(cards: [Card; 5], rules: &Rules) -> Self {
+        Self {
+            cards,
+            hand_type: rules.hand_type(&cards),
+            wild_as: rules.wild_as(&cards),
+            key: rules.tie_break_key(&cards),
+        }
+    }
+
+    pub fn parse(s: &str, rules: &Rules) -> Result<Self, ParseError> {
+        let cards = s
+            .char_indices()
+            .map(|(i, c)| Card::try_from(c).located(s, &s[i..i + c.len_utf8()]))
+            .collect::<Result<Vec<_>, _>>()?
+            .as_slice()
+            .try_into()
+            .map_err(|_| ParseError::at(s, s, "A hand has 5 cards"))?;
+
+        Ok(Self::new(cards, rules))
+    }
+
+    pub fn hand_type(&self) -> HandType {
+        self.hand_type
+    }
+
+    pub fn wild_as(&self) -> Option<Card> {
+        self.wild_as
+    }
+}
+
+impl Display for Hand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.cards.iter().map(|card| char::from(*card)).collect::<String>())
+    }
+}
+
+/// Hands are equal when they rank the same, even with different cards
+impl PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Hand {}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Weakest hand first
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .hand_type
+            .cmp(&self.hand_type)
+            .then_with(|| self.key.cmp(&other.key))
+    }
+}
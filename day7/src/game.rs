@@ -0,0 +1,116 @@
+use itertools::Itertools;
+use puzzle::{Locate, ParseError, Within};
+
+use crate::{cards::Hand, rules::Rules};
+
+/// A hand of the game and its place in the ranking
+pub struct Ranked<'a> {
+    pub hand: &'a Hand,
+    /// 1 for the weakest hand
+    pub rank: u64,
+    pub bid: u64,
+}
+
+impl Ranked<'_> {
+    /// Contribution of the hand to the total winnings
+    pub fn winning(&self) -> u64 {
+        self.rank * self.bid
+    }
+}
+
+/// The hands and their bids, played with some rules
+pub struct Game {
+    rules: Rules,
+    hands: Vec<(Hand, u64)>,
+}
+
+impl Game {
+    pub fn parse(s: &str, rules: Rules) -> Result<Self, ParseError> {
+        let hands = s
+            .lines()
+            .map(|line| {
+                let (hand, bid) = line
+                    .split_once(' ')
+                    .ok_or_else(|| ParseError::at(s, line, "Expected a hand and a bid"))?;
+                Ok((Hand::parse(hand, &rules).within(s, hand)?, bid.parse().located(s, bid)?))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { rules, hands })
+    }
+
+    pub fn rules(&self) -> &Rules {
+        &self.rules
+    }
+
+    /// The hands from the weakest to the strongest, with what they win
+    pub fn ranking(&self) -> Vec<Ranked<'_>> {
+        self.hands
+            .iter()
+            .sorted_by(|(hand, _), (other_hand, _)| hand.cmp(other_hand))
+            .enumerate()
+            .map(|(i, (hand, bid))| Ranked { hand, rank: i as u64 + 1, bid: *bid })
+            .collect()
+    }
+
+    pub fn total_wining(&self) -> u64 {
+        self.ranking().iter().map(Ranked::winning).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Error;
+
+    use super::*;
+
+    static EXAMPLE: &str = r#"32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483"#;
+
+    #[test]
+    fn test_part1_example() -> Result<(), Error> {
+        let game = Game::parse(EXAMPLE, Rules::standard())?;
+        let expected = 6440;
+
+        let actual = game.total_wining();
+
+        assert_eq!(expected, actual);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2_example() -> Result<(), Error> {
+        let game = Game::parse(EXAMPLE, Rules::jokers())?;
+        let expected = 5905;
+
+        let actual = game.total_wining();
+
+        assert_eq!(expected, actual);
+        Ok(())
+    }
+
+    #[test]
+    fn test_hand_order() -> Result<(), Error> {
+        let standard = Rules::standard();
+        let jokers = Rules::jokers();
+
+        assert!(Hand::parse("KK677", &standard)? > Hand::parse("KTJJT", &standard)?);
+        assert!(Hand::parse("KK677", &jokers)? < Hand::parse("KTJJT", &jokers)?);
+        assert!(Hand::parse("JKKK2", &jokers)? < Hand::parse("QQQQ2", &jokers)?);
+        assert_eq!(Hand::parse("T55J5", &standard)?, Hand::parse("T55J5", &standard)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error_position() {
+        let error = Game::parse("32T3K 765\nT55X5 684", Rules::standard()).err().unwrap();
+        assert_eq!((error.line, error.column, error.snippet.as_str()), (2, 4, "X"));
+
+        let error = Game::parse("32T3K 765\nT55 684", Rules::standard()).err().unwrap();
+        assert_eq!((error.line, error.column, error.snippet.as_str()), (2, 1, "T55"));
+    }
+}
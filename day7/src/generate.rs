@@ -0,0 +1,26 @@
+use std::collections::HashSet;
+
+use puzzle::Generator;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::CamelCards;
+
+static CARDS: [char; 13] = [
+    '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+];
+
+/// `size` is the number of hands, all distinct so the ranking is not ambiguous
+impl Generator for CamelCards {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        let mut hands = HashSet::new();
+        let mut lines = Vec::new();
+        while lines.len() < size.min(CARDS.len().pow(5)) {
+            let hand: String = (0..5).map(|_| *CARDS.choose(rng).unwrap()).collect();
+            if hands.insert(hand.clone()) {
+                lines.push(format!("{} {}", hand, rng.gen_range(1..=1000)));
+            }
+        }
+
+        lines.join("\n")
+    }
+}
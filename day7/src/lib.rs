@@ -0,0 +1,51 @@
+use anyhow::Error;
+use puzzle::{Answer, Solver};
+
+mod cards;
+mod game;
+mod generate;
+mod report;
+mod rules;
+
+pub use cards::{Card, Hand, HandType};
+pub use game::{Game, Ranked};
+pub use report::ReportFormat;
+pub use rules::{Rules, TieBreak};
+
+/// Default puzzle input
+pub static INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+/// The list of hands and bids, played with both rule sets
+pub struct CamelCards {
+    /// Standard rules, `J` is a Jack
+    standard: Game,
+    /// New rules, `J` is a Joker
+    jokers: Game,
+}
+
+impl CamelCards {
+    pub fn standard(&self) -> &Game {
+        &self.standard
+    }
+
+    pub fn jokers(&self) -> &Game {
+        &self.jokers
+    }
+}
+
+impl Solver for CamelCards {
+    fn parse(input: &str) -> Result<Self, Error> {
+        Ok(Self {
+            standard: Game::parse(input, Rules::standard())?,
+            jokers: Game::parse(input, Rules::jokers())?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, Error> {
+        Ok(self.standard.total_wining().into())
+    }
+
+    fn part2(&self) -> Result<Answer, Error> {
+        Ok(self.jokers.total_wining().into())
+    }
+}
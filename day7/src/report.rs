@@ -0,0 +1,133 @@
+use itertools::Itertools;
+
+use crate::{cards::Hand, game::Game, rules::Rules};
+
+/// How the ranking of a game is written
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// Aligned columns, for humans
+    Table,
+    /// Comma separated values with a header line, to diff or load elsewhere
+    Csv,
+}
+
+static HEADER: [&str; 6] = ["cards", "type", "wildcards", "rank", "bid", "winning"];
+
+/// Which cards of the hand are wild and what they stand for, "JJ=K", empty without wildcards
+fn wildcards(hand: &Hand, rules: &Rules) -> String {
+    match hand.wild_as() {
+        Some(card) => {
+            let wild: String = hand
+                .cards
+                .iter()
+                .filter(|card| rules.is_wild(**card))
+                .map(|card| char::from(*card))
+                .collect();
+            format!("{}={}", wild, char::from(card))
+        }
+        None => String::new(),
+    }
+}
+
+impl Game {
+    /// Every hand with its type, wildcards, rank, bid and winning, weakest first
+    pub fn report(&self, format: ReportFormat) -> String {
+        let rows = self
+            .ranking()
+            .iter()
+            .map(|ranked| {
+                [
+                    ranked.hand.to_string(),
+                    format!("{:?}", ranked.hand.hand_type()),
+                    wildcards(ranked.hand, self.rules()),
+                    ranked.rank.to_string(),
+                    ranked.bid.to_string(),
+                    ranked.winning().to_string(),
+                ]
+            })
+            .collect_vec();
+
+        match format {
+            ReportFormat::Csv => std::iter::once(HEADER.join(","))
+                .chain(rows.iter().map(|row| row.join(",")))
+                .map(|line| line + "\n")
+                .collect(),
+            ReportFormat::Table => {
+                let widths: Vec<usize> = (0..HEADER.len())
+                    .map(|i| {
+                        rows.iter()
+                            .map(|row| row[i].len())
+                            .chain([HEADER[i].len()])
+                            .max()
+                            .unwrap_or_default()
+                    })
+                    .collect();
+
+                // Text left aligned, numbers right aligned
+                let line = |row: &[&str]| {
+                    row.iter()
+                        .zip(&widths)
+                        .enumerate()
+                        .map(|(i, (cell, width))| match i {
+                            0..=2 => format!("{:<width$}", cell),
+                            _ => format!("{:>width$}", cell),
+                        })
+                        .join("  ")
+                        .trim_end()
+                        .to_string()
+                        + "\n"
+                };
+
+                let mut table = line(&HEADER);
+                for row in &rows {
+                    table += &line(&row.iter().map(String::as_str).collect_vec());
+                }
+                table + &format!("Total winnings: {}\n", self.total_wining())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Error;
+
+    use super::*;
+
+    static EXAMPLE: &str = r#"32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483"#;
+
+    #[test]
+    fn test_csv_report() -> Result<(), Error> {
+        let game = Game::parse(EXAMPLE, Rules::jokers())?;
+        let expected = r#"cards,type,wildcards,rank,bid,winning
+32T3K,OnePair,,1,765,765
+KK677,TwoPair,,2,28,56
+T55J5,FourOfAKind,J=5,3,684,2052
+QQQJA,FourOfAKind,J=Q,4,483,1932
+KTJJT,FourOfAKind,JJ=T,5,220,1100
+"#;
+
+        assert_eq!(expected, game.report(ReportFormat::Csv));
+        Ok(())
+    }
+
+    #[test]
+    fn test_table_report() -> Result<(), Error> {
+        let game = Game::parse(EXAMPLE, Rules::standard())?;
+        let expected = r#"cards  type          wildcards  rank  bid  winning
+32T3K  OnePair                     1  765      765
+KTJJT  TwoPair                     2  220      440
+KK677  TwoPair                     3   28       84
+T55J5  ThreeOfAKind                4  684     2736
+QQQJA  ThreeOfAKind                5  483     2415
+Total winnings: 6440
+"#;
+
+        assert_eq!(expected, game.report(ReportFormat::Table));
+        Ok(())
+    }
+}
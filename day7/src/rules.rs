@@ -0,0 +1,257 @@
+use anyhow::{anyhow, Error};
+use itertools::Itertools;
+
+use crate::cards::{Card, HandType};
+
+/// How two hands of the same type are ranked
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TieBreak {
+    /// Compare the cards one by one, in the order they were dealt
+    Dealt,
+    /// Compare the most repeated cards first, then the strongest, like poker.
+    /// Wildcards keep their own strength.
+    Grouped,
+}
+
+/// A rule set of Camel Cards
+#[derive(Clone, Debug)]
+pub struct Rules {
+    /// Strength of each card, indexed by `Card`, the weakest is 0
+    strength: [u8; 13],
+    /// Cards standing for whichever card makes the best hand, indexed by `Card`
+    wildcards: [bool; 13],
+    tie_break: TieBreak,
+}
+
+impl Rules {
+    /// Cards ranked as `order` lists them, weakest first, without wildcard.
+    /// `order` must list every card once.
+    pub fn new(order: [Card; 13]) -> Result<Self, Error> {
+        if let Some(card) = order.iter().duplicates().next() {
+            return Err(anyhow!("Card {:?} is ranked more than once", card));
+        }
+
+        Ok(Self::ranked(order))
+    }
+
+    fn ranked(order: [Card; 13]) -> Self {
+        let mut strength = [0; 13];
+        for (i, card) in order.iter().enumerate() {
+            strength[*card as usize] = i as u8;
+        }
+
+        Self { strength, wildcards: [false; 13], tie_break: TieBreak::Dealt }
+    }
+
+    pub fn with_wildcards(mut self, wildcards: &[Card]) -> Self {
+        self.wildcards = [false; 13];
+        for card in wildcards {
+            self.wildcards[*card as usize] = true;
+        }
+        self
+    }
+
+    pub fn with_tie_break(mut self, tie_break: TieBreak) -> Self {
+        self.tie_break = tie_break;
+        self
+    }
+
+    /// Part 1 rules, `J` is a Jack
+    pub fn standard() -> Self {
+        Self::ranked(Card::ALL)
+    }
+
+    /// Part 2 rules, `J` is a Joker, the weakest card but a wildcard
+    pub fn jokers() -> Self {
+        let mut order = Card::ALL;
+        order[..=Card::CJ as usize].rotate_right(1);
+
+        Self::ranked(order).with_wildcards(&[Card::CJ])
+    }
+
+    pub fn strength(&self, card: Card) -> u8 {
+        self.strength[card as usize]
+    }
+
+    pub fn is_wild(&self, card: Card) -> bool {
+        self.wildcards[card as usize]
+    }
+
+    pub fn hand_type(&self, cards: &[Card; 5]) -> HandType {
+        let mut counts = [0u8; 13];
+        let mut wild = 0;
+        for card in cards {
+            if self.is_wild(*card) {
+                wild += 1;
+            } else {
+                counts[*card as usize] += 1;
+            }
+        }
+
+        // Only the two biggest groups matter
+        let (mut first, mut second) = (0, 0);
+        for count in counts {
+            if count > first {
+                second = first;
+                first = count;
+            } else if count > second {
+                second = count;
+            }
+        }
+
+        // Wildcards all become the card that has the most repetition
+        match (first + wild, second) {
+            (5, _) => HandType::FiveOfAKind,
+            (4, _) => HandType::FourOfAKind,
+            (3, 2) => HandType::FullHouse,
+            (3, _) => HandType::ThreeOfAKind,
+            (2, 2) => HandType::TwoPair,
+            (2, _) => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+
+    /// The card the wildcards of a hand become to make the best hand: the most repeated
+    /// other card, the strongest on a tie, or the strongest card if they are all wild
+    pub fn wild_as(&self, cards: &[Card; 5]) -> Option<Card> {
+        if !cards.iter().any(|card| self.is_wild(*card)) {
+            return None;
+        }
+
+        let mut counts = [0u8; 13];
+        for card in cards.iter().filter(|card| !self.is_wild(**card)) {
+            counts[*card as usize] += 1;
+        }
+
+        Card::ALL
+            .into_iter()
+            .filter(|card| !self.is_wild(*card))
+            .max_by_key(|card| (counts[*card as usize], self.strength(*card)))
+    }
+
+    /// Strength of the cards in the order they break ties
+    pub(crate) fn tie_break_key(&self, cards: &[Card; 5]) -> [u8; 5] {
+        match self.tie_break {
+            TieBreak::Dealt => cards.map(|card| self.strength(card)),
+            TieBreak::Grouped => {
+                let mut counts = [0u8; 13];
+                for card in cards {
+                    counts[*card as usize] += 1;
+                }
+
+                let mut key = cards.map(|card| (counts[card as usize], self.strength(card)));
+                key.sort_unstable_by(|a, b| b.cmp(a));
+                key.map(|(_, strength)| strength)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cards::Hand;
+
+    use super::*;
+
+    #[test]
+    fn test_hand_type_recognition() -> Result<(), Error> {
+        let rules = Rules::standard();
+
+        assert_eq!(
+            HandType::FiveOfAKind,
+            rules.hand_type(&[Card::CA, Card::CA, Card::CA, Card::CA, Card::CA])
+        );
+        assert_eq!(
+            HandType::FourOfAKind,
+            rules.hand_type(&[Card::CA, Card::CA, Card::CA, Card::C2, Card::CA])
+        );
+        assert_eq!(
+            HandType::FullHouse,
+            rules.hand_type(&[Card::CA, Card::CA, Card::C2, Card::CA, Card::C2])
+        );
+        assert_eq!(
+            HandType::ThreeOfAKind,
+            rules.hand_type(&[Card::CA, Card::C2, Card::CA, Card::C3, Card::CA])
+        );
+        assert_eq!(
+            HandType::TwoPair,
+            rules.hand_type(&[Card::CA, Card::C3, Card::C2, Card::C2, Card::C3])
+        );
+        assert_eq!(
+            HandType::OnePair,
+            rules.hand_type(&[Card::C3, Card::C2, Card::C4, Card::C3, Card::C5])
+        );
+        assert_eq!(
+            HandType::HighCard,
+            rules.hand_type(&[Card::C2, Card::C4, Card::C5, Card::C6, Card::CA])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hand_type_recognition_with_jocker() -> Result<(), Error> {
+        let rules = Rules::jokers();
+
+        assert_eq!(
+            HandType::FiveOfAKind,
+            rules.hand_type(&[Card::CA, Card::CJ, Card::CA, Card::CA, Card::CA])
+        );
+        assert_eq!(
+            HandType::FourOfAKind,
+            rules.hand_type(&[Card::CA, Card::CA, Card::CJ, Card::C2, Card::CA])
+        );
+        assert_eq!(
+            HandType::ThreeOfAKind,
+            rules.hand_type(&[Card::CA, Card::C2, Card::CJ, Card::C3, Card::CA])
+        );
+        assert_eq!(
+            HandType::OnePair,
+            rules.hand_type(&[Card::C3, Card::C2, Card::C4, Card::CJ, Card::C5])
+        );
+        assert_eq!(
+            HandType::FiveOfAKind,
+            rules.hand_type(&[Card::CJ, Card::CJ, Card::CJ, Card::CJ, Card::CJ])
+        );
+        assert!(rules.strength(Card::CJ) < rules.strength(Card::C2));
+
+        assert_eq!(None, rules.wild_as(&[Card::C3, Card::C2, Card::C4, Card::C3, Card::C5]));
+        assert_eq!(
+            Some(Card::CT),
+            rules.wild_as(&[Card::CK, Card::CJ, Card::CJ, Card::CT, Card::CT])
+        );
+        assert_eq!(
+            Some(Card::CA),
+            rules.wild_as(&[Card::CJ, Card::CJ, Card::CJ, Card::CJ, Card::CJ])
+        );
+        assert_eq!(
+            Some(Card::CK),
+            rules.wild_as(&[Card::CK, Card::CJ, Card::CQ, Card::CT, Card::C2])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_variants() -> Result<(), Error> {
+        let hand = |cards, rules: &Rules| Hand::new(cards, rules);
+
+        // Deuces and jacks wild
+        let rules = Rules::standard().with_wildcards(&[Card::C2, Card::CJ]);
+        assert_eq!(
+            HandType::FourOfAKind,
+            rules.hand_type(&[Card::C2, Card::C3, Card::CJ, Card::C3, Card::C5])
+        );
+
+        // Poker like, the three of a kind decides between two full houses
+        let rules = Rules::standard().with_tie_break(TieBreak::Grouped);
+        let low = [Card::CA, Card::CA, Card::C3, Card::C3, Card::C3];
+        let high = [Card::C4, Card::C4, Card::C4, Card::C2, Card::C2];
+        assert!(hand(low, &rules) < hand(high, &rules));
+        assert!(hand(low, &Rules::standard()) > hand(high, &Rules::standard()));
+
+        assert!(Rules::new([Card::C2; 13]).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+use anyhow::{anyhow, Error};
+use day8::{Map, Overlay};
+use std::str::FromStr;
+
+/// Print the network as a Graphviz graph: dot [--steps] [--ghosts] [input]
+fn main() -> Result<(), Error> {
+    let mut overlays = Vec::new();
+    let mut path = day8::INPUT_PATH.to_string();
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--steps" => overlays.push(Overlay::Steps),
+            "--ghosts" => overlays.push(Overlay::Ghosts),
+            _ if arg.starts_with("--") => return Err(anyhow!("Unknown option '{}'", arg)),
+            _ => path = arg,
+        }
+    }
+
+    let input = puzzle::read_input(&path)?;
+    let map = Map::from_str(&input)?;
+
+    print!("{}", map.to_dot(&overlays)?);
+
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use std::{collections::HashMap, iter};
+
+use anyhow::Error;
+use itertools::Itertools;
+
+use crate::{Direction, Map, NodeMatch};
+
+/// Colors of the paths drawn over the network, in turn
+static COLORS: [&str; 6] = ["red", "blue", "darkorange", "purple", "darkgreen", "brown"];
+
+/// A path to draw over the network
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overlay {
+    /// The walk of part 1, from `AAA` to `ZZZ`
+    Steps,
+    /// The walk of each ghost until its cycle is complete
+    Ghosts,
+}
+
+impl Map {
+    /// Edges taken by a path, the node left and the direction followed
+    fn edges<'a>(&'a self, path: &'a [usize]) -> impl Iterator<Item = (usize, Direction)> + 'a {
+        path.iter()
+            .tuple_windows()
+            .enumerate()
+            .map(|(step, (from, _))| (*from, self.instructions[step % self.instructions.len()]))
+    }
+
+    /// The network as a Graphviz graph, start nodes in green, end nodes in red,
+    /// edges labelled with their direction and colored by the paths of `overlays`
+    pub fn to_dot(&self, overlays: &[Overlay]) -> Result<String, Error> {
+        let mut paths = Vec::new();
+        for overlay in overlays {
+            match overlay {
+                Overlay::Steps => {
+                    let walks = self.walk(&NodeMatch::exact("AAA"), &NodeMatch::exact("ZZZ"))?;
+                    paths.extend(walks.into_values().map(|walk| walk.path));
+                }
+                Overlay::Ghosts => {
+                    let ghosts = self.ghosts().into_iter().sorted_by_key(|(id, _)| self.name(*id));
+                    for (start, track) in ghosts {
+                        let steps = (track.prefix + track.cycle) as usize;
+                        let path = iter::once(start).chain(self.route(start)).take(steps + 1);
+                        paths.push(path.collect());
+                    }
+                }
+            }
+        }
+
+        let mut colors: HashMap<(usize, usize), Vec<&str>> = HashMap::new();
+        for (path, color) in paths.iter().zip(COLORS.iter().cycle()) {
+            for (from, direction) in self.edges(path).unique() {
+                colors.entry((from, direction as usize)).or_default().push(color);
+            }
+        }
+
+        let mut dot = String::from("digraph network {\n");
+        for (id, name) in self.names.iter().enumerate() {
+            let fill = if name.ends_with('A') {
+                " style=filled fillcolor=palegreen"
+            } else if name.ends_with('Z') {
+                " style=filled fillcolor=salmon"
+            } else {
+                ""
+            };
+            dot += &format!("    n{} [label=\"{}\"{}]\n", id, name, fill);
+        }
+
+        for (id, [left, right]) in self.network.iter().enumerate() {
+            let edges = if left == right {
+                vec![(*left, "LR", [Direction::Left, Direction::Right].as_slice())]
+            } else {
+                vec![
+                    (*left, "L", [Direction::Left].as_slice()),
+                    (*right, "R", [Direction::Right].as_slice()),
+                ]
+            };
+
+            for (to, label, directions) in edges {
+                let colors = directions
+                    .iter()
+                    .flat_map(|direction| colors.get(&(id, *direction as usize)))
+                    .flatten()
+                    .unique()
+                    .join(":");
+                let color = if colors.is_empty() {
+                    String::new()
+                } else {
+                    format!(" color=\"{}\" penwidth=2", colors)
+                };
+                dot += &format!("    n{} -> n{} [label=\"{}\"{}]\n", id, to, label, color);
+            }
+        }
+        dot += "}\n";
+
+        Ok(dot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_dot() -> Result<(), Error> {
+        let map = Map::from_str(
+            r#"LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)"#,
+        )?;
+        let expected = r#"digraph network {
+    n0 [label="AAA" style=filled fillcolor=palegreen]
+    n1 [label="BBB"]
+    n2 [label="ZZZ" style=filled fillcolor=salmon]
+    n0 -> n1 [label="LR" color="red" penwidth=2]
+    n1 -> n0 [label="L" color="red" penwidth=2]
+    n1 -> n2 [label="R" color="red" penwidth=2]
+    n2 -> n2 [label="LR"]
+}
+"#;
+
+        assert_eq!(expected, map.to_dot(&[Overlay::Steps])?);
+        assert!(!map.to_dot(&[])?.contains("penwidth"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ghost_overlays() -> Result<(), Error> {
+        let map = Map::from_str(
+            r#"L
+
+11A = (11Z, 11Z)
+11Z = (11Z, 11Z)
+22A = (11Z, 11Z)"#,
+        )?;
+
+        let dot = map.to_dot(&[Overlay::Ghosts])?;
+
+        assert!(dot.contains("n0 -> n1 [label=\"LR\" color=\"red\" penwidth=2]"));
+        assert!(dot.contains("n2 -> n1 [label=\"LR\" color=\"blue\" penwidth=2]"));
+        assert!(dot.contains("n1 -> n1 [label=\"LR\" color=\"red:blue\" penwidth=2]"));
+
+        let error = map.to_dot(&[Overlay::Steps]).err().unwrap();
+        assert_eq!("No node 'AAA' in the network", error.to_string());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+use std::collections::HashSet;
+
+use puzzle::Generator;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::Map;
+
+/// A random node name, never ending with `A` or `Z`
+fn name(rng: &mut StdRng, used: &mut HashSet<String>, last: Option<char>) -> String {
+    loop {
+        let mut name: String = (0..2).map(|_| rng.gen_range('A'..='Z')).collect();
+        name.push(last.unwrap_or_else(|| rng.gen_range('B'..='Y')));
+        if used.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+/// `size` is roughly the number of nodes.
+///
+/// Each ghost track goes from its start through two lanes of nodes to its end in the same
+/// number of steps whatever the instructions, then loops back to the node after its
+/// start. So each track cycles, with the first track going from `AAA` to `ZZZ`.
+impl Generator for Map {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        let size = size.max(4);
+        let instructions: String = (0..rng.gen_range(2..=size))
+            .map(|_| if rng.gen_bool(0.5) { 'L' } else { 'R' })
+            .collect();
+
+        let tracks = rng.gen_range(1..=(size / 4).clamp(1, 5));
+        let mut used = HashSet::from(["AAA".to_string(), "ZZZ".to_string()]);
+        let mut lines = Vec::new();
+        for track in 0..tracks {
+            let (start, end) = if track == 0 {
+                ("AAA".to_string(), "ZZZ".to_string())
+            } else {
+                (
+                    name(rng, &mut used, Some('A')),
+                    name(rng, &mut used, Some('Z')),
+                )
+            };
+
+            let length = rng.gen_range(2..=(size / tracks / 2).max(2));
+            let lanes: Vec<(String, String)> = (1..length)
+                .map(|_| (name(rng, &mut used, None), name(rng, &mut used, None)))
+                .collect();
+
+            let (first_left, first_right) = lanes[0].clone();
+            lines.push(format!("{} = ({}, {})", start, first_left, first_right));
+            for (i, (left, right)) in lanes.iter().enumerate() {
+                let next = match lanes.get(i + 1) {
+                    Some((next_left, next_right)) if rng.gen_bool(0.5) => {
+                        format!("({}, {})", next_left, next_right)
+                    }
+                    Some((next_left, next_right)) => format!("({}, {})", next_right, next_left),
+                    None => format!("({}, {})", end, end),
+                };
+                lines.push(format!("{} = {}", left, next));
+                lines.push(format!("{} = {}", right, next));
+            }
+            lines.push(format!("{} = ({}, {})", end, first_left, first_right));
+        }
+        lines.shuffle(rng);
+
+        format!("{}\n\n{}", instructions, lines.join("\n"))
+    }
+}
@@ -0,0 +1,259 @@
+use std::{collections::HashMap, iter};
+
+use anyhow::{anyhow, Error};
+use lcmx::lcmx;
+
+use crate::{Map, NodeMatch};
+
+/// When a ghost is on an end node, found walking its states (node, instruction index)
+/// until one repeats: after `prefix` steps it loops every `cycle` steps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Track {
+    /// Steps before entering the cycle
+    pub prefix: u64,
+    /// Length of the cycle
+    pub cycle: u64,
+    /// Steps on an end node before entering the cycle
+    pub early_ends: Vec<u64>,
+    /// Steps on an end node during the first turn of the cycle, from `prefix` to `prefix + cycle`
+    pub ends: Vec<u64>,
+}
+
+impl Track {
+    pub fn is_end(&self, step: u64) -> bool {
+        if step < self.prefix {
+            self.early_ends.contains(&step)
+        } else {
+            let step = self.prefix + (step - self.prefix) % self.cycle;
+            self.ends.contains(&step)
+        }
+    }
+
+    /// The ghost is on an end node exactly every multiple of some number of steps
+    fn is_periodic(&self) -> bool {
+        self.early_ends.is_empty() && matches!(self.ends.as_slice(), [end] if end % self.cycle == 0)
+    }
+}
+
+/// Smallest `x` with `x ≡ a1 mod m1` and `x ≡ a2 mod m2`, and the modulus of all the solutions
+fn crt((a1, m1): (i128, i128), (a2, m2): (i128, i128)) -> Result<Option<(i128, i128)>, Error> {
+    // Extended Euclid, g = gcd(m1, m2) = m1 * u + m2 * v
+    let (mut g, mut next_g, mut u, mut next_u) = (m1, m2, 1i128, 0i128);
+    while next_g != 0 {
+        let q = g / next_g;
+        (g, next_g) = (next_g, g - q * next_g);
+        (u, next_u) = (next_u, u - q * next_u);
+    }
+
+    if (a2 - a1) % g != 0 {
+        return Ok(None);
+    }
+
+    let modulus = m1 / g * m2;
+    let k = ((a2 - a1) / g % (m2 / g)) * (u % (m2 / g)) % (m2 / g);
+    let x = m1
+        .checked_mul(k)
+        .and_then(|x| x.checked_add(a1))
+        .ok_or_else(|| anyhow!("Cycles are too long to be combined"))?;
+
+    Ok(Some((x.rem_euclid(modulus), modulus)))
+}
+
+impl Map {
+    /// Walk from `start` until a state repeats
+    pub fn track(&self, start: usize, is_end: impl Fn(usize) -> bool) -> Track {
+        let mut seen = HashMap::new();
+        let mut ends = Vec::new();
+        let mut repeat = (0, 0);
+        for (step, location) in (0..).zip(iter::once(start).chain(self.route(start))) {
+            let instruction = step as usize % self.instructions.len();
+            if let Some(first) = seen.insert((location, instruction), step) {
+                repeat = (first, step - first);
+                break;
+            }
+            if is_end(location) {
+                ends.push(step);
+            }
+        }
+
+        let (prefix, cycle) = repeat;
+
+        let (early_ends, ends) = ends.into_iter().partition(|end| *end < prefix);
+
+        Track { prefix, cycle, early_ends, ends }
+    }
+
+    /// The track of each ghost, from every node ending with `A` to nodes ending with `Z`,
+    /// by start node id
+    pub fn ghosts(&self) -> HashMap<usize, Track> {
+        self.tracks(&NodeMatch::suffix("A"), &NodeMatch::suffix("Z"))
+    }
+
+    /// The LCM of the cycles gives the steps for all the ghosts to be on an end node
+    pub fn lcm_holds(tracks: &[Track]) -> bool {
+        tracks.iter().all(Track::is_periodic)
+            && lcmx(&tracks.iter().map(|track| track.cycle).collect::<Vec<_>>())
+                .is_some_and(|lcm| tracks.iter().all(|track| track.ends[0] <= lcm))
+    }
+
+    /// First step, after the start, when all the tracks are on an end node at the same time
+    pub fn first_common_end(tracks: &[Track]) -> Result<Option<u64>, Error> {
+        let Some(last) = tracks.iter().max_by_key(|track| track.prefix) else {
+            return Ok(None);
+        };
+
+        if Self::lcm_holds(tracks) {
+            return Ok(lcmx(&tracks.iter().map(|track| track.cycle).collect::<Vec<_>>()));
+        }
+
+        // While the last ghost has not entered its cycle, it can only be on its early ends
+        if let Some(step) = last
+            .early_ends
+            .iter()
+            .find(|step| **step > 0 && tracks.iter().all(|track| track.is_end(**step)))
+        {
+            return Ok(Some(*step));
+        }
+
+        // Then all the ghosts cycle, try every combination of ends
+        let mut solutions = vec![(0, 1)];
+        for track in tracks {
+            let cycle = track.cycle as i128;
+            let mut combined = Vec::new();
+            for solution in &solutions {
+                for end in &track.ends {
+                    if let Some(solution) = crt(*solution, (*end as i128 % cycle, cycle))? {
+                        combined.push(solution);
+                    }
+                }
+            }
+            combined.sort();
+            combined.dedup();
+            solutions = combined;
+        }
+
+        let start = last.prefix.max(1) as i128;
+        solutions
+            .into_iter()
+            .map(|(x, modulus)| x + ((start - x).max(0) + modulus - 1) / modulus * modulus)
+            .min()
+            .map(u64::try_from)
+            .transpose()
+            .map_err(|_| anyhow!("Too many steps"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    /// Move all the ghosts one step at a time
+    fn brute_force(map: &Map, limit: u64) -> Option<u64> {
+        let mut routes: Vec<_> = (0..map.names.len())
+            .filter(|id| map.name(*id).ends_with('A'))
+            .map(|id| map.route(id))
+            .collect();
+        (1..=limit).find(|_| {
+            let locations: Vec<usize> = routes.iter_mut().flat_map(|route| route.next()).collect();
+            locations.iter().all(|location| map.name(*location).ends_with('Z'))
+        })
+    }
+
+    static PARALLEL: &str = r#"LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)"#;
+
+    #[test]
+    fn test_tracks() -> Result<(), Error> {
+        let map = Map::from_str(PARALLEL)?;
+        let ghosts = map.ghosts();
+        let id = |name| map.id(name).unwrap();
+
+        assert_eq!(
+            Track { prefix: 1, cycle: 2, early_ends: vec![], ends: vec![2] },
+            ghosts[&id("11A")]
+        );
+        assert_eq!(
+            Track { prefix: 1, cycle: 6, early_ends: vec![], ends: vec![3, 6] },
+            ghosts[&id("22A")]
+        );
+        assert!(!Map::lcm_holds(&ghosts.into_values().collect::<Vec<_>>()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_offset_cycles() -> Result<(), Error> {
+        // The second ghost is on an end node 1 step after each turn of its cycle
+        let map = Map::from_str(
+            r#"L
+
+11A = (11B, 11B)
+11B = (11Z, 11Z)
+11Z = (11B, 11B)
+22A = (22Z, 22Z)
+22Z = (22B, 22B)
+22B = (22C, 22C)
+22C = (22Z, 22Z)"#,
+        )?;
+        let tracks: Vec<Track> = map.ghosts().into_values().collect();
+
+        assert!(!Map::lcm_holds(&tracks));
+        assert_eq!(Some(4), Map::first_common_end(&tracks)?);
+        assert_eq!(Some(4), brute_force(&map, 100));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_never_together() -> Result<(), Error> {
+        let map = Map::from_str(
+            r#"L
+
+11A = (11Z, 11Z)
+11Z = (11B, 11B)
+11B = (11Z, 11Z)
+22A = (22B, 22B)
+22B = (22Z, 22Z)
+22Z = (22B, 22B)"#,
+        )?;
+        let tracks: Vec<Track> = map.ghosts().into_values().collect();
+
+        assert_eq!(None, Map::first_common_end(&tracks)?);
+        assert_eq!(None, brute_force(&map, 100));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_generated_against_brute_force() -> Result<(), Error> {
+        use puzzle::Generator;
+
+        for seed in 0..20 {
+            let map = Map::from_str(&Map::generate(12, seed))?;
+            let tracks: Vec<Track> = map.ghosts().into_values().collect();
+
+            assert_eq!(brute_force(&map, 100_000), Map::first_common_end(&tracks)?);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_crt() -> Result<(), Error> {
+        assert_eq!(Some((4, 6)), crt((0, 2), (1, 3))?);
+        assert_eq!(Some((7, 12)), crt((3, 4), (1, 6))?);
+        assert_eq!(None, crt((0, 4), (1, 6))?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,272 @@
+use anyhow::{anyhow, Error};
+use puzzle::{Answer, Locate, ParseError, Solver};
+use std::{collections::HashMap, str::FromStr};
+
+mod dot;
+mod generate;
+mod ghosts;
+mod walk;
+
+pub use dot::Overlay;
+pub use ghosts::Track;
+pub use walk::{NodeMatch, Walk};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Direction {
+    Left = 0,
+    Right,
+}
+
+impl TryFrom<char> for Direction {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'L' => Ok(Self::Left),
+            'R' => Ok(Self::Right),
+            _ => Err(anyhow!("Invalid direction char: '{}'", value)),
+        }
+    }
+}
+
+/// The network, with nodes known by their index in `names`
+#[derive(Debug)]
+pub struct Map {
+    instructions: Vec<Direction>,
+    names: Vec<String>,
+    ids: HashMap<String, usize>,
+    /// Left and right nodes of each node
+    network: Vec<[usize; 2]>,
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (instructions, network) = s.split_once("\n\n").ok_or_else(|| {
+            ParseError::at_end(s, "Expected instructions and network separated by an empty line")
+        })?;
+
+        let instructions_line = instructions;
+        let instructions = instructions
+            .char_indices()
+            .map(|(i, c)| {
+                Direction::try_from(c).located(s, &instructions[i..i + c.len_utf8()])
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if instructions.is_empty() {
+            return Err(ParseError::at(s, instructions_line, "No instructions"));
+        }
+
+        let nodes = network
+            .lines()
+            .map(|line| {
+                let (location, next) = line
+                    .split_once(" = ")
+                    .ok_or_else(|| ParseError::at(s, line, "Expected 'NODE = (LEFT, RIGHT)'"))?;
+
+                let (left, right) = next
+                    .strip_prefix('(')
+                    .and_then(|next| next.strip_suffix(')'))
+                    .and_then(|next| next.split_once(", "))
+                    .ok_or_else(|| ParseError::at(s, next, "Expected '(LEFT, RIGHT)'"))?;
+
+                Ok((location, left, right))
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        // Number the nodes in the order they are defined, then link them
+        let mut names = Vec::new();
+        let mut ids = HashMap::new();
+        for (location, _, _) in &nodes {
+            if ids.insert(location.to_string(), names.len()).is_some() {
+                return Err(ParseError::at(
+                    s,
+                    location,
+                    format!("Node '{}' is defined twice", location),
+                ));
+            }
+            names.push(location.to_string());
+        }
+
+        let mut network = vec![[0, 0]; names.len()];
+        for (location, left, right) in nodes {
+            let id = |name: &str| {
+                ids.get(name)
+                    .copied()
+                    .ok_or_else(|| ParseError::at(s, name, format!("Undefined node '{}'", name)))
+            };
+            network[ids[location]] = [id(left)?, id(right)?];
+        }
+
+        Ok(Self { instructions, names, ids, network })
+    }
+}
+
+impl Map {
+    /// Index of a node from its name
+    pub fn id(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: usize) -> &str {
+        &self.names[id]
+    }
+
+    fn steps(&self) -> Result<u64, Error> {
+        // Part 1 only, the ghosts of part 2 do not need them
+        let [start, end] = ["AAA", "ZZZ"]
+            .map(|name| self.id(name).ok_or_else(|| anyhow!("No node '{}' in the network", name)));
+        let (start, end) = (start?, end?);
+
+        self.walk_from(start, |location| location == end, |_| {})
+            .ok_or_else(|| self.loop_error(start, "ZZZ", |location| location == end))
+    }
+
+    /// Node reached from `location` following the instruction at `index`
+    fn next(&self, location: usize, index: usize) -> usize {
+        self.network[location][self.instructions[index] as usize]
+    }
+
+    /// Steps for all the ghosts to be on an end node at the same time
+    fn parallel_steps(&self) -> Result<u64, Error> {
+        let tracks: Vec<Track> = self.ghosts().into_values().collect();
+        if tracks.is_empty() {
+            return Err(anyhow!("No ghost, no node name ends with 'A'"));
+        }
+
+        Self::first_common_end(&tracks)?
+            .ok_or_else(|| anyhow!("Ghosts are never all on an end node at the same time"))
+    }
+}
+
+/// Default puzzle input
+pub static INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+impl Solver for Map {
+    fn parse(input: &str) -> Result<Self, Error> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> Result<Answer, Error> {
+        Ok(self.steps()?.into())
+    }
+
+    fn part2(&self) -> Result<Answer, Error> {
+        Ok(self.parallel_steps()?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_1() -> Result<(), Error> {
+        let map = Map::from_str(
+            r#"RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)"#,
+        )?;
+        let expected = 2;
+
+        let actual = map.steps()?;
+
+        assert_eq!(expected, actual);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_example_2() -> Result<(), Error> {
+        let map = Map::from_str(
+            r#"LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)"#,
+        )?;
+        let expected = 6;
+
+        let actual = map.steps()?;
+
+        assert_eq!(expected, actual);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parallel_steps() -> Result<(), Error> {
+        let map = Map::parse(
+            r#"LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)"#,
+        )?;
+        let expected = Answer::from(6u64);
+
+        let actual = map.part2()?;
+
+        assert_eq!(expected, actual);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_node_ids() -> Result<(), Error> {
+        let map = Map::from_str("LR\n\nZZZ = (AAA, ZZZ)\nAAA = (ZZZ, AAA)")?;
+
+        assert_eq!((Some(0), Some(1), None), (map.id("ZZZ"), map.id("AAA"), map.id("BBB")));
+        assert_eq!("AAA", map.name(1));
+        assert_eq!(vec![[1, 0], [0, 1]], map.network);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_networks() -> Result<(), Error> {
+        let map = Map::parse("LR\n\nAAA = (AAA, AAA)")?;
+        assert_eq!("No node 'ZZZ' in the network", map.part1().err().unwrap().to_string());
+
+        let map = Map::parse("LR\n\nAAA = (BBB, BBB)\nBBB = (CCC, CCC)\nCCC = (BBB, BBB)\nZZZ = (ZZZ, ZZZ)")?;
+        assert_eq!(
+            "ZZZ is never reached from AAA, the walk loops from step 1 every 2 steps",
+            map.steps().err().unwrap().to_string()
+        );
+
+        let map = Map::from_str("L\n\n11B = (11Z, 11Z)\n11Z = (11B, 11B)")?;
+        assert!(map.parallel_steps().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error_position() {
+        let error = Map::from_str("LXR\n\nAAA = (ZZZ, ZZZ)").err().unwrap();
+        assert_eq!((error.line, error.column, error.snippet.as_str()), (1, 2, "X"));
+
+        let error = Map::from_str("LR\n\nAAA = (ZZZ, ZZZ)\nZZZ = ZZZ, ZZZ").err().unwrap();
+        assert_eq!((error.line, error.column, error.snippet.as_str()), (4, 7, "ZZZ, ZZZ"));
+
+        let error = Map::from_str("LR\n\nAAA = (ZZZ, BBB)\nZZZ = (ZZZ, ZZZ)").err().unwrap();
+        assert_eq!((error.line, error.column, error.snippet.as_str()), (3, 13, "BBB"));
+
+        let error = Map::from_str("LR\n\nAAA = (AAA, AAA)\nAAA = (AAA, AAA)").err().unwrap();
+        assert_eq!((error.line, error.column, error.snippet.as_str()), (4, 1, "AAA"));
+
+        let error = Map::from_str("\n\nAAA = (AAA, AAA)").err().unwrap();
+        assert_eq!((error.line, error.message.as_str()), (1, "No instructions"));
+    }
+}
@@ -0,0 +1,213 @@
+use std::{collections::HashMap, fmt::Display};
+
+use anyhow::{anyhow, Error};
+use regex::Regex;
+
+use crate::{ghosts::Track, Map};
+
+/// Which nodes a walk starts from or stops on
+pub enum NodeMatch {
+    /// The node with this name
+    Exact(String),
+    /// Nodes whose name ends with this
+    Suffix(String),
+    /// Nodes whose name matches
+    Regex(Regex),
+    /// Nodes whose name passes the test
+    Custom(Box<dyn Fn(&str) -> bool>),
+}
+
+impl NodeMatch {
+    pub fn exact(name: impl Into<String>) -> Self {
+        Self::Exact(name.into())
+    }
+
+    pub fn suffix(suffix: impl Into<String>) -> Self {
+        Self::Suffix(suffix.into())
+    }
+
+    pub fn regex(regex: &str) -> Result<Self, Error> {
+        Ok(Self::Regex(Regex::new(regex)?))
+    }
+
+    pub fn custom(test: impl Fn(&str) -> bool + 'static) -> Self {
+        Self::Custom(Box::new(test))
+    }
+
+    pub fn matches(&self, name: &str) -> bool {
+        match self {
+            Self::Exact(exact) => name == exact,
+            Self::Suffix(suffix) => name.ends_with(suffix.as_str()),
+            Self::Regex(regex) => regex.is_match(name),
+            Self::Custom(test) => test(name),
+        }
+    }
+}
+
+impl Display for NodeMatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Exact(exact) => write!(f, "{}", exact),
+            Self::Suffix(suffix) => write!(f, "a node ending with '{}'", suffix),
+            Self::Regex(regex) => write!(f, "a node matching /{}/", regex),
+            Self::Custom(_) => write!(f, "a goal node"),
+        }
+    }
+}
+
+/// A walk from a start node to the first goal node
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Walk {
+    pub steps: u64,
+    /// Every node visited, from the start to the goal
+    pub path: Vec<usize>,
+}
+
+impl Map {
+    /// Does each node match
+    fn mask(&self, nodes: &NodeMatch) -> Vec<bool> {
+        self.names.iter().map(|name| nodes.matches(name)).collect()
+    }
+
+    /// The node reached at each step walking from `start`, endlessly
+    pub fn route(&self, start: usize) -> impl Iterator<Item = usize> + '_ {
+        (0..self.instructions.len()).cycle().scan(start, move |location, index| {
+            *location = self.next(*location, index);
+            Some(*location)
+        })
+    }
+
+    /// Count the steps from `start` to the first goal node, at least one step away,
+    /// calling `visit` on every node reached. `None` if the walk loops without reaching a goal.
+    pub(crate) fn walk_from(
+        &self,
+        start: usize,
+        is_goal: impl Fn(usize) -> bool,
+        mut visit: impl FnMut(usize),
+    ) -> Option<u64> {
+        // There are only so many (node, instruction index) states, walking longer than
+        // that means a state repeated and the walk loops without reaching a goal
+        let states = (self.names.len() * self.instructions.len()) as u64;
+
+        for (steps, location) in (1..=states + 1).zip(self.route(start)) {
+            visit(location);
+            if is_goal(location) {
+                return Some(steps);
+            }
+        }
+
+        None
+    }
+
+    /// Why a walk from `start` never reaches `goal`
+    pub(crate) fn loop_error(
+        &self,
+        start: usize,
+        goal: impl Display,
+        is_goal: impl Fn(usize) -> bool,
+    ) -> Error {
+        let track = self.track(start, is_goal);
+
+        anyhow!(
+            "{} is never reached from {}, the walk loops from step {} every {} steps",
+            goal,
+            self.name(start),
+            track.prefix,
+            track.cycle
+        )
+    }
+
+    /// Walk from each `start` node to the first `goal` node, at least one step away.
+    /// Fails if no node is a start, or if a walk loops without reaching a goal.
+    pub fn walk(&self, start: &NodeMatch, goal: &NodeMatch) -> Result<HashMap<String, Walk>, Error> {
+        let goals = self.mask(goal);
+
+        let mut walks = HashMap::new();
+        for first in (0..self.names.len()).filter(|id| start.matches(self.name(*id))) {
+            let mut path = vec![first];
+            let is_goal = |location: usize| goals[location];
+            let steps = self
+                .walk_from(first, is_goal, |location| path.push(location))
+                .ok_or_else(|| self.loop_error(first, goal, is_goal))?;
+
+            walks.insert(self.name(first).to_string(), Walk { steps, path });
+        }
+
+        if walks.is_empty() {
+            return Err(match start {
+                NodeMatch::Exact(name) => anyhow!("No node '{}' in the network", name),
+                _ => anyhow!("No start node in the network, expected {}", start),
+            });
+        }
+
+        Ok(walks)
+    }
+
+    /// The track of each `start` node to the `goal` nodes, by start node id
+    pub fn tracks(&self, start: &NodeMatch, goal: &NodeMatch) -> HashMap<usize, Track> {
+        let goals = self.mask(goal);
+
+        (0..self.names.len())
+            .filter(|id| start.matches(self.name(*id)))
+            .map(|id| (id, self.track(id, |location| goals[location])))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    static NETWORK: &str = r#"LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)"#;
+
+    #[test]
+    fn test_walk() -> Result<(), Error> {
+        let map = Map::from_str(NETWORK)?;
+        let id = |name| map.id(name).unwrap();
+
+        let walks = map.walk(&NodeMatch::exact("AAA"), &NodeMatch::suffix("Z"))?;
+        assert_eq!(
+            Walk {
+                steps: 6,
+                path: vec![id("AAA"), id("BBB"), id("AAA"), id("BBB"), id("AAA"), id("BBB"), id("ZZZ")]
+            },
+            walks["AAA"]
+        );
+
+        let walks = map.walk(&NodeMatch::regex("^[AB]")?, &NodeMatch::custom(|name| name == "ZZZ"))?;
+        assert_eq!((6, 3), (walks["AAA"].steps, walks["BBB"].steps));
+
+        // The start itself is not the goal, the walk has to leave it
+        let walks = map.walk(&NodeMatch::exact("ZZZ"), &NodeMatch::exact("ZZZ"))?;
+        assert_eq!(vec![id("ZZZ"), id("ZZZ")], walks["ZZZ"].path);
+
+        assert_eq!(
+            vec![id("BBB"), id("AAA"), id("BBB"), id("AAA")],
+            map.route(id("AAA")).take(4).collect::<Vec<_>>()
+        );
+        assert_eq!(Some(3), map.walk_from(id("BBB"), |location| location == id("ZZZ"), |_| {}));
+        assert_eq!(None, map.walk_from(id("ZZZ"), |location| location == id("AAA"), |_| {}));
+
+        let error = map.walk(&NodeMatch::exact("QQQ"), &NodeMatch::suffix("Z")).err().unwrap();
+        assert_eq!("No node 'QQQ' in the network", error.to_string());
+        let error = map.walk(&NodeMatch::suffix("Q"), &NodeMatch::suffix("Z")).err().unwrap();
+        assert_eq!(
+            "No start node in the network, expected a node ending with 'Q'",
+            error.to_string()
+        );
+
+        let error = map.walk(&NodeMatch::exact("AAA"), &NodeMatch::suffix("Q")).err().unwrap();
+        assert_eq!(
+            "a node ending with 'Q' is never reached from AAA, the walk loops from step 6 every 3 steps",
+            error.to_string()
+        );
+
+        Ok(())
+    }
+}
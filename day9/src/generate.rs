@@ -0,0 +1,33 @@
+use puzzle::Generator;
+use rand::{rngs::StdRng, Rng};
+
+use crate::{binomial, Report};
+
+const HISTORY_LEN: i128 = 21;
+
+/// `size` is the number of sequences, each one the first values of a random polynomial
+impl Generator for Report {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                // In the binomial basis, integer coefficients give integer values
+                let coefficients: Vec<i128> = (0..=rng.gen_range(0..=6))
+                    .map(|_| rng.gen_range(-10..=10))
+                    .collect();
+
+                (0..HISTORY_LEN)
+                    .map(|x| {
+                        coefficients
+                            .iter()
+                            .zip(0..)
+                            .map(|(c, k)| c * binomial(x, k).expect("small binomial"))
+                            .sum::<i128>()
+                            .to_string()
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
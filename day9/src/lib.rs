@@ -0,0 +1,224 @@
+use anyhow::{anyhow, Error};
+use itertools::Itertools;
+use puzzle::{Answer, Locate, ParseError, Solver, Within};
+use std::str::FromStr;
+
+mod generate;
+
+/// The polynomial of smallest degree going through the values of a history, in Newton
+/// forward difference form: value(x) = Σ Δᵏ(0) · C(x, k), with `x` the index in the history
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial {
+    /// First value of each row of the difference table, until a row is all zeros
+    differences: Vec<i128>,
+}
+
+/// `x` choose `k`, for any integer `x`: x (x - 1) ... (x - k + 1) / k!
+pub(crate) fn binomial(x: i128, k: usize) -> Option<i128> {
+    // A product of i + 1 consecutive integers is a multiple of (i + 1)!, divisions are exact
+    (0..k as i128).try_fold(1i128, |acc, i| Some(acc.checked_mul(x.checked_sub(i)?)? / (i + 1)))
+}
+
+impl Polynomial {
+    pub fn new(history: &[i64]) -> Result<Self, Error> {
+        let mut row: Vec<i128> = history.iter().map(|value| *value as i128).collect();
+        let mut differences = Vec::new();
+        while row.iter().any(|value| *value != 0) {
+            differences.push(row[0]);
+            row = row
+                .iter()
+                .tuple_windows()
+                .map(|(prev, next)| next.checked_sub(*prev))
+                .collect::<Option<_>>()
+                .ok_or_else(|| anyhow!("Differences of the history are too big"))?;
+        }
+
+        Ok(Self { differences })
+    }
+
+    pub fn degree(&self) -> usize {
+        self.differences.len().saturating_sub(1)
+    }
+
+    /// Value at index `x`, negative before the history, beyond its length after it
+    pub fn value_at(&self, x: i128) -> Result<i128, Error> {
+        self.differences
+            .iter()
+            .enumerate()
+            .try_fold(0i128, |value, (k, difference)| {
+                binomial(x, k)
+                    .and_then(|binomial| binomial.checked_mul(*difference))
+                    .and_then(|term| value.checked_add(term))
+            })
+            .ok_or_else(|| anyhow!("Value at {} is too big", x))
+    }
+}
+
+struct Sequence {
+    history: Vec<i64>,
+    polynomial: Polynomial,
+}
+
+impl FromStr for Sequence {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let history: Vec<i64> = s
+            .split(' ')
+            .map(|value| value.parse().located(s, value))
+            .collect::<Result<_, _>>()?;
+        let polynomial = Polynomial::new(&history).located(s, s)?;
+
+        Ok(Self { history, polynomial })
+    }
+}
+
+impl Sequence {
+    /// Value `steps` after the last one of the history
+    fn extrapolate(&self, steps: i128) -> Result<i128, Error> {
+        self.polynomial.value_at(self.history.len() as i128 - 1 + steps)
+    }
+
+    /// Value `steps` before the first one of the history
+    fn back_extrapolate(&self, steps: i128) -> Result<i128, Error> {
+        self.polynomial.value_at(-steps)
+    }
+}
+
+pub struct Report {
+    sequences: Vec<Sequence>,
+}
+
+impl FromStr for Report {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let sequences = s
+            .lines()
+            .map(|line| Sequence::from_str(line).within(s, line))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Report { sequences })
+    }
+}
+
+impl Report {
+    /// Sum of the values `steps` after the end of each sequence
+    pub fn extrapolation_sum(&self, steps: i128) -> Result<i128, Error> {
+        self.sequences.iter().try_fold(0i128, |sum, s| {
+            sum.checked_add(s.extrapolate(steps)?)
+                .ok_or_else(|| anyhow!("Sum of the predictions is too big"))
+        })
+    }
+
+    /// Sum of the values `steps` before the start of each sequence
+    pub fn back_extrapolation_sum(&self, steps: i128) -> Result<i128, Error> {
+        self.sequences.iter().try_fold(0i128, |sum, s| {
+            sum.checked_add(s.back_extrapolate(steps)?)
+                .ok_or_else(|| anyhow!("Sum of the back-predictions is too big"))
+        })
+    }
+}
+
+/// Default puzzle input
+pub static INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+impl Solver for Report {
+    fn parse(input: &str) -> Result<Self, Error> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> Result<Answer, Error> {
+        Ok(Answer::Number(self.extrapolation_sum(1)?))
+    }
+
+    fn part2(&self) -> Result<Answer, Error> {
+        Ok(Answer::Number(self.back_extrapolation_sum(1)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EXAMPLE: &str = r#"0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45"#;
+
+    #[test]
+    fn test_example_parsing() -> Result<(), Error> {
+        let report = Report::from_str(EXAMPLE)?;
+        let expected_sequences = 3;
+        let expected_seq_len = 6;
+
+        assert_eq!(expected_sequences, report.sequences.len());
+        assert_eq!(expected_seq_len, report.sequences[0].history.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_extrapolate_forward_result() -> Result<(), Error> {
+        let report = Report::from_str(EXAMPLE)?;
+        let expected = 114;
+
+        let actual = report.extrapolation_sum(1)?;
+
+        assert_eq!(expected, actual);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_extrapolate_backward_result() -> Result<(), Error> {
+        let report = Report::from_str(EXAMPLE)?;
+        let expected = 2;
+
+        let actual = report.back_extrapolation_sum(1)?;
+
+        assert_eq!(expected, actual);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_polynomial() -> Result<(), Error> {
+        // n² + 1
+        let polynomial = Polynomial::new(&[1, 2, 5, 10, 17])?;
+
+        assert_eq!(2, polynomial.degree());
+        assert_eq!(vec![1, 1, 2], polynomial.differences);
+        assert_eq!(50, polynomial.value_at(7)?);
+        assert_eq!(2, polynomial.value_at(-1)?);
+        assert_eq!(10i128.pow(24) + 1, polynomial.value_at(10i128.pow(12))?);
+        assert_eq!(10i128.pow(24) + 1, polynomial.value_at(-(10i128.pow(12)))?);
+        assert!(polynomial.value_at(10i128.pow(20)).is_err());
+
+        assert_eq!(0, Polynomial::new(&[0, 0, 0])?.value_at(1000)?);
+        // A single value is a constant
+        assert_eq!(4, Polynomial::new(&[4])?.value_at(-3)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_extrapolate_far() -> Result<(), Error> {
+        let report = Report::from_str(EXAMPLE)?;
+
+        // 3n and (n + 1)(n + 2) / 2, 100 steps after the history
+        assert_eq!(report.extrapolation_sum(1)?, 114);
+        assert_eq!(report.sequences[0].extrapolate(100)?, 3 * 105);
+        assert_eq!(report.sequences[1].extrapolate(100)?, 106 * 107 / 2);
+        assert_eq!(report.sequences[2].back_extrapolate(1)?, 5);
+        assert_eq!(report.sequences[2].back_extrapolate(2)?, -4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error_position() {
+        let error = Report::from_str("0 3 6\n1 3 six 10").err().unwrap();
+
+        assert_eq!((error.line, error.column, error.snippet.as_str()), (2, 5, "six"));
+    }
+}
@@ -0,0 +1,120 @@
+use std::ops::{Add, Sub};
+
+/// Position of a cell, x grows to the right and y grows down
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Coordinate {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Coordinate {
+    pub fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Manhattan distance, number of up/down/left/right steps to go from one to the other
+    pub fn distance(&self, other: Coordinate) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+/// Displacement between two coordinates
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Vec2d {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Vec2d {
+    pub const UP: Vec2d = Vec2d { x: 0, y: -1 };
+    pub const DOWN: Vec2d = Vec2d { x: 0, y: 1 };
+    pub const LEFT: Vec2d = Vec2d { x: -1, y: 0 };
+    pub const RIGHT: Vec2d = Vec2d { x: 1, y: 0 };
+
+    pub fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Rotate by 90 degrees, clockwise on screen since y grows down
+    pub fn rotate_90(self) -> Self {
+        Vec2d {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+}
+
+/// Up, right, down and left
+pub const DIRECTIONS4: [Vec2d; 4] = [Vec2d::UP, Vec2d::RIGHT, Vec2d::DOWN, Vec2d::LEFT];
+
+/// The 4 directions and the 4 diagonals, clockwise starting up
+pub const DIRECTIONS8: [Vec2d; 8] = [
+    Vec2d::UP,
+    Vec2d { x: 1, y: -1 },
+    Vec2d::RIGHT,
+    Vec2d { x: 1, y: 1 },
+    Vec2d::DOWN,
+    Vec2d { x: -1, y: 1 },
+    Vec2d::LEFT,
+    Vec2d { x: -1, y: -1 },
+];
+
+impl Sub<Coordinate> for Coordinate {
+    type Output = Vec2d;
+
+    fn sub(self, rhs: Coordinate) -> Self::Output {
+        Vec2d {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl Add<Vec2d> for Coordinate {
+    type Output = Coordinate;
+
+    fn add(self, rhs: Vec2d) -> Self::Output {
+        Coordinate {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl Sub<Vec2d> for Coordinate {
+    type Output = Coordinate;
+
+    fn sub(self, rhs: Vec2d) -> Self::Output {
+        Coordinate {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distance() {
+        let a = Coordinate::new(1, 6);
+        let b = Coordinate::new(5, 11);
+
+        assert_eq!(9, a.distance(b));
+        assert_eq!(9, b.distance(a));
+        assert_eq!(0, a.distance(a));
+    }
+
+    #[test]
+    fn test_operations() {
+        let a = Coordinate::new(3, 4);
+        let b = Coordinate::new(1, 1);
+
+        assert_eq!(Vec2d::new(2, 3), a - b);
+        assert_eq!(a, b + (a - b));
+        assert_eq!(b, a - (a - b));
+        assert_eq!(Vec2d::RIGHT, Vec2d::UP.rotate_90());
+        assert_eq!(Vec2d::UP, Vec2d::UP.rotate_90().rotate_90().rotate_90().rotate_90());
+    }
+}
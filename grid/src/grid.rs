@@ -0,0 +1,278 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use puzzle::ParseError;
+
+use crate::{Coordinate, Vec2d, DIRECTIONS4, DIRECTIONS8};
+
+/// Dense rectangular grid of cells, stored row by row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid of `width` x `height` cells all set to `value`
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Parse a character map, one line per row, converting each character to a cell
+    pub fn parse<E: Display>(
+        s: &str,
+        mut cell: impl FnMut(Coordinate, char) -> Result<T, E>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for (y, line) in s.lines().enumerate() {
+            let line_width = line.chars().count();
+            if *width.get_or_insert(line_width) != line_width {
+                return Err(ParseError::new(
+                    y + 1,
+                    1,
+                    line,
+                    format!(
+                        "Line is {} cells wide, expected {}",
+                        line_width,
+                        width.unwrap_or_default()
+                    ),
+                ));
+            }
+
+            for (x, c) in line.chars().enumerate() {
+                let value = cell(Coordinate::new(x as i64, y as i64), c)
+                    .map_err(|e| ParseError::new(y + 1, x + 1, &c.to_string(), e))?;
+                cells.push(value);
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Is the coordinate inside the grid
+    pub fn contains(&self, position: Coordinate) -> bool {
+        (0..self.width as i64).contains(&position.x) && (0..self.height as i64).contains(&position.y)
+    }
+
+    /// Is the coordinate on the first or last row or column of the grid
+    pub fn is_border(&self, position: Coordinate) -> bool {
+        self.contains(position)
+            && (position.x == 0
+                || position.y == 0
+                || position.x == self.width as i64 - 1
+                || position.y == self.height as i64 - 1)
+    }
+
+    fn index_of(&self, position: Coordinate) -> Option<usize> {
+        if self.contains(position) {
+            Some(position.y as usize * self.width + position.x as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, position: Coordinate) -> Option<&T> {
+        self.index_of(position).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, position: Coordinate) -> Option<&mut T> {
+        self.index_of(position).map(|i| &mut self.cells[i])
+    }
+
+    /// All the coordinates of the grid, row by row
+    pub fn coordinates(&self) -> impl Iterator<Item = Coordinate> {
+        let width = self.width as i64;
+        (0..self.height as i64).flat_map(move |y| (0..width).map(move |x| Coordinate::new(x, y)))
+    }
+
+    /// All the cells with their coordinate, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Coordinate, &T)> {
+        self.coordinates().zip(self.cells.iter())
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        position: Coordinate,
+        directions: &'a [Vec2d],
+    ) -> impl Iterator<Item = Coordinate> + 'a {
+        directions
+            .iter()
+            .map(move |direction| position + *direction)
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// Up, right, down and left neighbours that are inside the grid
+    pub fn neighbours4(&self, position: Coordinate) -> impl Iterator<Item = Coordinate> + '_ {
+        self.neighbours(position, &DIRECTIONS4)
+    }
+
+    /// Neighbours inside the grid, including diagonals
+    pub fn neighbours8(&self, position: Coordinate) -> impl Iterator<Item = Coordinate> + '_ {
+        self.neighbours(position, &DIRECTIONS8)
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        if y < self.height {
+            Some(&self.cells[y * self.width..(y + 1) * self.width])
+        } else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).filter_map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        if x < self.width {
+            Some(self.cells.iter().skip(x).step_by(self.width))
+        } else {
+            None
+        }
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).filter_map(|x| self.column(x))
+    }
+
+    /// Render the grid as text, one line per row
+    pub fn render<D: Display>(&self, mut cell: impl FnMut(Coordinate, &T) -> D) -> String {
+        let mut text = String::new();
+        for (position, value) in self.iter() {
+            text += &cell(position, value).to_string();
+            if position.x == self.width as i64 - 1 {
+                text.push('\n');
+            }
+        }
+        text
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, |_, c| Ok::<_, ParseError>(c))
+    }
+}
+
+impl Display for Grid<char> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render(|_, c| *c))
+    }
+}
+
+impl<T> Index<Coordinate> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Coordinate) -> &Self::Output {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", position))
+    }
+}
+
+impl<T> IndexMut<Coordinate> for Grid<T> {
+    fn index_mut(&mut self, position: Coordinate) -> &mut Self::Output {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Error;
+
+    use super::*;
+
+    static EXAMPLE: &str = r#"#..
+.#.
+..#
+#.."#;
+
+    #[test]
+    fn test_parse() -> Result<(), Error> {
+        let grid = Grid::from_str(EXAMPLE)?;
+
+        assert_eq!(3, grid.width());
+        assert_eq!(4, grid.height());
+        assert_eq!('#', grid[Coordinate::new(1, 1)]);
+        assert_eq!(Some(&'.'), grid.get(Coordinate::new(2, 3)));
+        assert_eq!(None, grid.get(Coordinate::new(3, 0)));
+        assert_eq!(None, grid.get(Coordinate::new(0, -1)));
+        assert_eq!(
+            Err((2, 1)),
+            Grid::from_str("#..\n.#").map_err(|e| (e.line, e.column))
+        );
+        assert_eq!(
+            Err((2, 3, "x".to_string())),
+            Grid::parse("...\n..x", |_, c| match c {
+                '.' => Ok(()),
+                _ => Err("Bad cell"),
+            })
+            .map_err(|e| (e.line, e.column, e.snippet))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render() -> Result<(), Error> {
+        let grid = Grid::from_str(EXAMPLE)?;
+
+        assert_eq!(format!("{}\n", EXAMPLE), grid.to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_neighbours() -> Result<(), Error> {
+        let grid = Grid::from_str(EXAMPLE)?;
+
+        assert_eq!(2, grid.neighbours4(Coordinate::new(0, 0)).count());
+        assert_eq!(3, grid.neighbours8(Coordinate::new(0, 0)).count());
+        assert_eq!(4, grid.neighbours4(Coordinate::new(1, 1)).count());
+        assert_eq!(8, grid.neighbours8(Coordinate::new(1, 1)).count());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rows_and_columns() -> Result<(), Error> {
+        let grid = Grid::from_str(EXAMPLE)?;
+
+        assert_eq!(Some(&['.', '#', '.'][..]), grid.row(1));
+        assert_eq!(4, grid.rows().count());
+        assert_eq!(vec![&'.', &'.', &'#', &'.'], grid.column(2).unwrap().collect::<Vec<_>>());
+        assert_eq!(3, grid.columns().count());
+        assert!(grid.column(3).is_none());
+
+        Ok(())
+    }
+}
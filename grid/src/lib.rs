@@ -0,0 +1,5 @@
+mod coordinate;
+mod grid;
+
+pub use coordinate::{Coordinate, Vec2d, DIRECTIONS4, DIRECTIONS8};
+pub use grid::Grid;
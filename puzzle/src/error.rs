@@ -0,0 +1,149 @@
+use std::fmt::Display;
+
+/// Maximum length of the snippet shown in an error
+const SNIPPET_LEN: usize = 40;
+
+/// Error while parsing a puzzle input, with the position of the offending text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line number, starting at 1
+    pub line: usize,
+    /// Column number in characters, starting at 1
+    pub column: usize,
+    /// The text that could not be parsed
+    pub snippet: String,
+    pub message: String,
+}
+
+/// Byte offset of `inner` in `outer`, if `inner` is a slice of `outer`
+fn offset_of(outer: &str, inner: &str) -> Option<usize> {
+    let start = outer.as_ptr() as usize;
+    let inner_start = inner.as_ptr() as usize;
+
+    if inner_start >= start && inner_start + inner.len() <= start + outer.len() {
+        Some(inner_start - start)
+    } else {
+        None
+    }
+}
+
+/// Line and column of a byte offset
+fn position(s: &str, offset: usize) -> (usize, usize) {
+    let before = &s[..offset];
+    let line = before.matches('\n').count() + 1;
+    let column = before.rsplit('\n').next().unwrap_or_default().chars().count() + 1;
+    (line, column)
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, snippet: &str, message: impl Display) -> Self {
+        Self {
+            line,
+            column,
+            snippet: snippet.chars().take(SNIPPET_LEN).collect(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Error on `snippet`, a slice of the parsed `input`
+    pub fn at(input: &str, snippet: &str, message: impl Display) -> Self {
+        let offset = offset_of(input, snippet)
+            .or_else(|| input.find(snippet))
+            .unwrap_or(0);
+        let (line, column) = position(input, offset);
+        Self::new(line, column, snippet, message)
+    }
+
+    /// Error at the end of `input`, when something is missing
+    pub fn at_end(input: &str, message: impl Display) -> Self {
+        Self::at(input, &input[input.len()..], message)
+    }
+
+    /// Move an error found while parsing `inner`, a slice of `outer`, to its position in `outer`
+    pub fn within(self, outer: &str, inner: &str) -> Self {
+        let Some(offset) = offset_of(outer, inner) else {
+            return self;
+        };
+        let (line, column) = position(outer, offset);
+
+        Self {
+            column: if self.line == 1 {
+                self.column + column - 1
+            } else {
+                self.column
+            },
+            line: self.line + line - 1,
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)?;
+        if !self.snippet.is_empty() {
+            write!(f, " at '{}'", self.snippet)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Locate the error of a result at some text of the input
+pub trait Locate<T> {
+    /// Turn the error into a [`ParseError`] on `snippet`, a slice of the parsed `input`
+    fn located(self, input: &str, snippet: &str) -> Result<T, ParseError>;
+}
+
+impl<T, E: Display> Locate<T> for Result<T, E> {
+    fn located(self, input: &str, snippet: &str) -> Result<T, ParseError> {
+        self.map_err(|e| ParseError::at(input, snippet, e))
+    }
+}
+
+/// Move the error of a nested parser to its position in the outer input
+pub trait Within<T> {
+    fn within(self, outer: &str, inner: &str) -> Result<T, ParseError>;
+}
+
+impl<T> Within<T> for Result<T, ParseError> {
+    fn within(self, outer: &str, inner: &str) -> Result<T, ParseError> {
+        self.map_err(|e| e.within(outer, inner))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = "first line\nsecond line\nthird line";
+
+    #[test]
+    fn test_at() {
+        let error = ParseError::at(INPUT, &INPUT[18..22], "Bad word");
+
+        assert_eq!(2, error.line);
+        assert_eq!(8, error.column);
+        assert_eq!("line", error.snippet);
+        assert_eq!("line 2, column 8: Bad word at 'line'", error.to_string());
+    }
+
+    #[test]
+    fn test_at_end() {
+        let error = ParseError::at_end(INPUT, "Missing line");
+
+        assert_eq!((3, 11), (error.line, error.column));
+        assert_eq!("line 3, column 11: Missing line", error.to_string());
+    }
+
+    #[test]
+    fn test_within() {
+        let line = INPUT.lines().nth(2).unwrap();
+        let word = &line[6..];
+
+        let error = "x".parse::<u32>().located(line, word).within(INPUT, line);
+
+        assert_eq!(Err((3, 7, "line".to_string())), error.map_err(|e| (e.line, e.column, e.snippet)));
+    }
+}
@@ -0,0 +1,146 @@
+use std::{fmt::Display, fs, io::Read};
+
+use anyhow::{anyhow, Context, Error};
+use rand::{rngs::StdRng, SeedableRng};
+
+mod error;
+
+pub use error::{Locate, ParseError, Within};
+
+/// Answer to one part of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(t) => write!(f, "{}", t),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(u32, u64, usize, i32, i64);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+/// A day puzzle: parse the input once, then solve both parts from it
+pub trait Solver: Sized {
+    fn parse(input: &str) -> Result<Self, Error>;
+
+    fn part1(&self) -> Result<Answer, Error>;
+
+    fn part2(&self) -> Result<Answer, Error>;
+
+    /// Parse the input and solve one part of the puzzle
+    fn solve(input: &str, part: u8) -> Result<Answer, Error> {
+        let puzzle = Self::parse(input)?;
+        match part {
+            1 => puzzle.part1(),
+            2 => puzzle.part2(),
+            part => Err(anyhow!("Part {} does not exist", part)),
+        }
+    }
+}
+
+/// A day puzzle whose inputs can be generated, to test and benchmark beyond the real input
+pub trait Generator {
+    /// Largest `size` the inputs can have, bigger sizes are capped to it
+    const MAX_SIZE: usize = usize::MAX;
+
+    /// Random but valid input, `size` scales it: number of lines, side of the grid...
+    fn random_input(rng: &mut StdRng, size: usize) -> String;
+
+    /// Random input, the same for the same size and seed
+    fn generate(size: usize, seed: u64) -> String {
+        Self::random_input(&mut StdRng::seed_from_u64(seed), size)
+    }
+}
+
+/// Read a puzzle input from a file, or from stdin if the path is `-`
+pub fn read_input(path: &str) -> Result<String, Error> {
+    if path == "-" {
+        let mut input = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input)
+            .context("Cannot read input from stdin")?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path).with_context(|| format!("Cannot read input file '{}'", path))
+    }
+}
+
+/// Read the puzzle input from the path given as first command line argument,
+/// falling back to `default` if there is none
+pub fn input_from_args(default: &str) -> Result<String, Error> {
+    let path = std::env::args().nth(1).unwrap_or(default.to_string());
+    read_input(&path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Lines(usize);
+
+    impl Solver for Lines {
+        fn parse(input: &str) -> Result<Self, Error> {
+            Ok(Self(input.lines().count()))
+        }
+
+        fn part1(&self) -> Result<Answer, Error> {
+            Ok(self.0.into())
+        }
+
+        fn part2(&self) -> Result<Answer, Error> {
+            Ok(format!("{} lines", self.0).into())
+        }
+    }
+
+    impl Generator for Lines {
+        fn random_input(rng: &mut StdRng, size: usize) -> String {
+            use rand::Rng;
+
+            (0..size).map(|_| rng.gen_range(0..100).to_string()).collect::<Vec<_>>().join("\n")
+        }
+    }
+
+    #[test]
+    fn test_generate() -> Result<(), Error> {
+        let input = Lines::generate(10, 42);
+
+        assert_eq!(input, Lines::generate(10, 42));
+        assert_ne!(input, Lines::generate(10, 43));
+        assert_eq!(Answer::Number(10), Lines::solve(&input, 1)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_solve() -> Result<(), Error> {
+        assert_eq!(Answer::Number(3), Lines::solve("a\nb\nc", 1)?);
+        assert_eq!("3 lines", Lines::solve("a\nb\nc", 2)?.to_string());
+        assert!(Lines::solve("a\nb\nc", 3).is_err());
+
+        Ok(())
+    }
+}